@@ -106,8 +106,8 @@ fn apply_sequence_p2(sequence: &Sequence, dial: &i32) -> (i32, i32) {
 
 pub fn solve(input: &str) -> SolutionPair {
     let parsed_input = parse(input);
-    let sol1 = solve_p1(&parsed_input).to_string();
-    let sol2 = solve_p2(&parsed_input).to_string();
+    let sol1 = solve_p1(&parsed_input);
+    let sol2 = solve_p2(&parsed_input);
 
     (Solution::from(sol1), Solution::from(sol2))
 }
@@ -120,47 +120,41 @@ mod tests {
     fn test_example_input_day1_p1() {
         let input = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82";
         let (p1, _) = solve(input);
-        let p1_result = format!("{p1}");
-        assert_eq!(p1_result, "3");
+        assert_eq!(p1, Solution::from(3));
     }
 
     #[test]
     fn test_example_input_large_num_day1_p1() {
         let input = "L168\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82";
         let (p1, _) = solve(input);
-        let p1_result = format!("{p1}");
-        assert_eq!(p1_result, "3");
+        assert_eq!(p1, Solution::from(3));
     }
 
     #[test]
     fn test_example_input_day1_p2() {
         let input = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82";
         let (_, p2) = solve(input);
-        let p2_result = format!("{p2}");
-        assert_eq!(p2_result, "6");
+        assert_eq!(p2, Solution::from(6));
     }
 
     #[test]
     fn test_example_input_large_num_day1_p2() {
         let input = "L168\nL30\nR448\nL5\nR60\nL55\nL1\nL99\nR14\nL82";
         let (_, p2) = solve(input);
-        let p2_result = format!("{p2}");
-        assert_eq!(p2_result, "11");
+        assert_eq!(p2, Solution::from(11));
     }
 
     #[test]
     fn test_example_input_zero_to_zero_day1_p2() {
         let input = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR100\nL100";
         let (_, p2) = solve(input);
-        let p2_result = format!("{p2}");
-        assert_eq!(p2_result, "7");
+        assert_eq!(p2, Solution::from(7));
     }
 
     #[test]
     fn test_left_large_movement_overcounting() {
         let input = "L50";
         let (_, p2) = solve(input);
-        let p2_result = format!("{p2}");
-        assert_eq!(p2_result, "1");
+        assert_eq!(p2, Solution::from(1));
     }
 }
@@ -1,70 +1,12 @@
 use std::collections::HashMap;
 
-use crate::{Solution, SolutionPair};
+use crate::{
+    Solution, SolutionPair,
+    utils::range::{Integer, Range},
+};
 
 ///////////////////////////////////////////////////////////////////////////////
 
-#[derive(Debug)]
-struct Range {
-    start: i64,
-    end: i64,
-}
-
-impl From<&str> for Range {
-    fn from(value: &str) -> Self {
-        let parts: Vec<&str> = value.split('-').collect();
-        Self {
-            start: parts[0].trim().parse().unwrap(),
-            end: parts[1].trim().parse().unwrap(),
-        }
-    }
-}
-
-/// Each range should be made of subranges that are the same # of digits
-fn split_range_into_subranges_p1(range: &Range) -> Vec<Range> {
-    let mut subranges: Vec<Range> = Vec::new();
-    let mut current_range: i64 = range.start;
-    let end: i64 = range.end;
-
-    while current_range <= end {
-        let digits = current_range.to_string().len() as u32;
-        let next_power: i64 = 10i64.pow(digits);
-        let end_current_range = std::cmp::min(end, next_power - 1) as i64;
-
-        if digits.is_multiple_of(2) {
-            subranges.push(Range {
-                start: current_range,
-                end: end_current_range,
-            });
-        }
-
-        current_range = next_power;
-    }
-
-    subranges
-}
-
-fn split_range_into_subranges_p2(range: &Range) -> Vec<Range> {
-    let mut subranges: Vec<Range> = Vec::new();
-    let mut current_range: i64 = range.start;
-    let end: i64 = range.end;
-
-    while current_range <= end {
-        let digits = current_range.to_string().len() as u32;
-        let next_power: i64 = 10i64.pow(digits);
-        let end_current_range = std::cmp::min(end, next_power - 1) as i64;
-
-        subranges.push(Range {
-            start: current_range,
-            end: end_current_range,
-        });
-
-        current_range = next_power;
-    }
-
-    subranges
-}
-
 fn get_sum_of_invalid_ids_in_range_p1(range: &Range) -> i64 {
     let digits = range.start.to_string().len();
     let half_sig_start: i64 = range
@@ -144,10 +86,15 @@ fn get_sum_of_invalid_ids_in_range_p2(range: &Range) -> i64 {
 }
 
 pub fn solve(input: &str) -> SolutionPair {
-    let input_ranges: Vec<Range> = input.split(',').map(Range::from).collect();
+    let input_ranges: Vec<Range> = input
+        .split(',')
+        .map(|range| range.parse().unwrap())
+        .collect();
+    // Only an even number of digits can be split into two repeated halves
     let all_ranges_p1: Vec<Range> = input_ranges
         .iter()
-        .flat_map(split_range_into_subranges_p1)
+        .flat_map(Range::split_by_digit_count)
+        .filter(|range| range.start.digit_count().is_multiple_of(2))
         .collect();
     let sol1: i64 = all_ranges_p1
         .iter()
@@ -156,7 +103,7 @@ pub fn solve(input: &str) -> SolutionPair {
 
     let all_ranges_p2: Vec<Range> = input_ranges
         .iter()
-        .flat_map(split_range_into_subranges_p2)
+        .flat_map(Range::split_by_digit_count)
         .collect();
     let sol2: i64 = all_ranges_p2
         .iter()
@@ -175,8 +122,7 @@ mod tests {
     fn test_example_input_day2_p1() {
         let input = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
         let (p1, _) = solve(input);
-        let p1_result = format!("{p1}");
-        assert_eq!(p1_result, "1227775554");
+        assert_eq!(p1, Solution::from(1227775554));
     }
 
     #[test]
@@ -185,7 +131,6 @@ mod tests {
 1698522-1698528,446443-446449,38593856-38593862,565653-565659,
 824824821-824824827,2121212118-2121212124";
         let (_, p2) = solve(input);
-        let p2_result = format!("{p2}");
-        assert_eq!(p2_result, "4174379265");
+        assert_eq!(p2, Solution::from(4174379265i64));
     }
 }
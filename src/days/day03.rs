@@ -107,15 +107,13 @@ mod tests {
     fn test_example_input_day3_p1() {
         let input = "987654321111111\n811111111111119\n234234234234278\n818181911112111";
         let (p1, _) = solve(input);
-        let p1_result = format!("{p1}");
-        assert_eq!(p1_result, "357");
+        assert_eq!(p1, Solution::from(357));
     }
 
     #[test]
     fn test_example_input_day3_p2() {
         let input = "987654321111111\n811111111111119\n234234234234278\n818181911112111";
         let (_, p2) = solve(input);
-        let p2_result = format!("{p2}");
-        assert_eq!(p2_result, "3121910778619");
+        assert_eq!(p2, Solution::from(3121910778619i64));
     }
 }
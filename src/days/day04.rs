@@ -1,6 +1,10 @@
 use crate::{
     Solution, SolutionPair,
-    utils::grid::{Grid, Point},
+    utils::{
+        bit_grid::BitGrid,
+        grid::{Grid, Point},
+        pathfinding::Neighborhood,
+    },
 };
 
 ///////////////////////////////////////////////////////////////////////////////
@@ -12,45 +16,20 @@ fn is_roll_accessible(point: &Point, grid: &Grid) -> bool {
 
 pub fn solve(input: &str) -> SolutionPair {
     let mut grid = Grid::new(input);
-    let sol1: i32 = grid
-        .iter_2d()
-        .filter_map(|point| {
-            if point.value == b'.' {
-                return None;
-            }
 
-            if is_roll_accessible(&point, &grid) {
-                return Some(1);
-            }
+    // counts the neighbours of a whole word of cells at a time
+    let rolls = BitGrid::from_grid(&grid, |point| point.value == b'@');
+    let sol1 = (&rolls & &rolls.neighbors_fewer_than(4)).count_ones();
 
-            None
-        })
-        .sum();
-
-    let mut sol2 = 0;
-    loop {
-        let mut accessible_points: Vec<Point> = Vec::new();
-        for point in grid.iter_2d() {
-            if point.value == b'@' && is_roll_accessible(&point, &grid) {
-                accessible_points.push(point);
-            }
-        }
-
-        if accessible_points.is_empty() {
-            break;
+    // accessible rolls are removed a generation at a time until none are left
+    grid.run_until_stable(Neighborhood::Eight, |grid, point| {
+        if point.value == b'@' && is_roll_accessible(point, grid) {
+            b'.'
+        } else {
+            point.value
         }
-
-        for point in accessible_points {
-            unsafe {
-                let ptr = grid
-                    .data
-                    .as_mut_ptr()
-                    .add(point.row * grid.row_size + point.col);
-                *ptr = b'.';
-                sol2 += 1;
-            }
-        }
-    }
+    });
+    let sol2 = rolls.count_ones() - grid.find_all(b'@').len();
 
     (Solution::from(sol1), Solution::from(sol2))
 }
@@ -63,15 +42,13 @@ mod tests {
     fn test_example_input_day4_p1() {
         let input = "..@@.@@@@.\n@@@.@.@.@@\n@@@@@.@.@@\n@.@@@@..@.\n@@.@@@@.@@\n.@@@@@@@.@\n.@.@.@.@@@\n@.@@@.@@@@\n.@@@@@@@@.\n@.@.@@@.@.";
         let (p1, _) = solve(input);
-        let p1_result = format!("{p1}");
-        assert_eq!(p1_result, "13");
+        assert_eq!(p1, Solution::from(13));
     }
 
     #[test]
     fn test_example_input_day4_p2() {
         let input = "..@@.@@@@.\n@@@.@.@.@@\n@@@@@.@.@@\n@.@@@@..@.\n@@.@@@@.@@\n.@@@@@@@.@\n.@.@.@.@@@\n@.@@@.@@@@\n.@@@@@@@@.\n@.@.@@@.@.";
         let (_, p2) = solve(input);
-        let p2_result = format!("{p2}");
-        assert_eq!(p2_result, "43");
+        assert_eq!(p2, Solution::from(43));
     }
 }
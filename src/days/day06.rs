@@ -1,4 +1,7 @@
-use crate::{Solution, SolutionPair};
+use crate::{
+    Solution, SolutionPair,
+    utils::grid::{Grid, Padding},
+};
 
 ///////////////////////////////////////////////////////////////////////////////
 
@@ -75,23 +78,19 @@ fn parse_p2(input: &str) -> Vec<Problem> {
         })
         .collect();
 
-    // get number for on each column
+    // get number for on each column, lines can have different trailing spaces
     let mut column_based_numbers: Vec<i64> = Vec::new();
-    let col_size = input.lines().next().unwrap().len();
-    let row_size = input.lines().count() - 1;
-
-    for i in 0..col_size {
-        let mut current_num: Vec<char> = Vec::new();
-        for j in 0..row_size {
-            let line = input.lines().nth(j).unwrap();
-            let ch = line.chars().nth(i).unwrap();
-            if ch != ' ' {
-                current_num.push(ch);
-            }
-        }
+    let grid = Grid::try_new_with(input, Padding::Fill(b' ')).unwrap();
+    let digits = grid.view(0..grid.num_rows() - 1, 0..grid.row_size);
+
+    for column in digits.iter_cols() {
+        let current_num: String = column
+            .filter(|&&ch| ch != b' ')
+            .map(|&ch| ch as char)
+            .collect();
 
         if !current_num.is_empty() {
-            let num: i64 = current_num.iter().collect::<String>().parse().unwrap();
+            let num: i64 = current_num.parse().unwrap();
             column_based_numbers.push(num);
         }
     }
@@ -146,14 +145,12 @@ mod tests {
     fn test_example_input_day6_p1() {
         let input = "123 328  51 64 \n 45 64  387 23 \n  6 98  215 314\n*   +   *   +  ";
         let (p1, _) = solve(input);
-        let p1_result = format!("{p1}");
-        assert_eq!(p1_result, "4277556");
+        assert_eq!(p1, Solution::from(4277556));
     }
     #[test]
     fn test_example_input_day6_p2() {
         let input = "123 328  51 64 \n 45 64  387 23 \n  6 98  215 314\n*   +   *   +  ";
         let (_, p2) = solve(input);
-        let p2_result = format!("{p2}");
-        assert_eq!(p2_result, "3263827");
+        assert_eq!(p2, Solution::from(3263827));
     }
 }
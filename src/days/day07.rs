@@ -2,15 +2,35 @@ use crate::{Solution, SolutionPair, utils::grid::Grid};
 
 ///////////////////////////////////////////////////////////////////////////////
 
-fn solve_quantum_manifolds(grid: &Grid) -> (i32, i128) {
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Tile {
+    Start,
+    Empty,
+    Splitter,
+}
+
+impl From<u8> for Tile {
+    fn from(value: u8) -> Self {
+        match value {
+            b'S' => Self::Start,
+            b'.' => Self::Empty,
+            b'^' => Self::Splitter,
+            _ => panic!("Bad input file value"),
+        }
+    }
+}
+
+fn solve_quantum_manifolds(grid: &Grid<Tile>) -> (i32, i128) {
+    let start = grid.find(Tile::Start).expect("No start in input");
     let mut splits: Vec<i128> = vec![0; grid.row_size];
+    splits[start.col] = 1;
+
     let mut sum = 0;
-    for row in grid.iter_rows() {
-        for (i, ch) in row.iter().enumerate() {
-            match ch {
-                b'S' => splits[i] = 1,
-                b'.' => continue,
-                b'^' => {
+    for row in grid.iter_rows().skip(start.row + 1) {
+        for (i, tile) in row.iter().enumerate() {
+            match tile {
+                Tile::Start | Tile::Empty => continue,
+                Tile::Splitter => {
                     let current_val_at_split = splits[i];
                     splits[i] = 0;
                     splits[i - 1] += current_val_at_split;
@@ -20,7 +40,6 @@ fn solve_quantum_manifolds(grid: &Grid) -> (i32, i128) {
                         sum += 1;
                     }
                 }
-                _ => panic!("Bad input file value"),
             }
         }
     }
@@ -29,7 +48,7 @@ fn solve_quantum_manifolds(grid: &Grid) -> (i32, i128) {
 }
 
 pub fn solve(input: &str) -> SolutionPair {
-    let grid = Grid::new(input);
+    let grid = Grid::parse_with(input, Tile::from);
     let (sol1, sol2) = solve_quantum_manifolds(&grid);
     (Solution::from(sol1), Solution::from(sol2))
 }
@@ -42,15 +61,13 @@ mod tests {
     fn test_example_input_day7_p1() {
         let input = ".......S.......\n...............\n.......^.......\n...............\n......^.^......\n...............\n.....^.^.^.....\n...............\n....^.^...^....\n...............\n...^.^...^.^...\n...............\n..^...^.....^..\n...............\n.^.^.^.^.^...^.\n...............";
         let (p1, _) = solve(input);
-        let p1_result = format!("{p1}");
-        assert_eq!(p1_result, "21");
+        assert_eq!(p1, Solution::from(21));
     }
 
     #[test]
     fn test_example_input_day7_p2() {
         let input = ".......S.......\n...............\n.......^.......\n...............\n......^.^......\n...............\n.....^.^.^.....\n...............\n....^.^...^....\n...............\n...^.^...^.^...\n...............\n..^...^.....^..\n...............\n.^.^.^.^.^...^.\n...............";
         let (_, p2) = solve(input);
-        let p2_result = format!("{p2}");
-        assert_eq!(p2_result, "40");
+        assert_eq!(p2, Solution::from(40));
     }
 }
@@ -1,27 +1,9 @@
 use std::collections::HashMap;
 
-use crate::{Solution, SolutionPair};
+use crate::{Solution, SolutionPair, utils::vec3::Vector3};
 
 ///////////////////////////////////////////////////////////////////////////////
 
-#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Copy, Clone)]
-struct Vector3 {
-    x: i64,
-    y: i64,
-    z: i64,
-}
-
-impl From<&str> for Vector3 {
-    fn from(value: &str) -> Self {
-        let nums: Vec<i64> = value.split(',').map(|num| num.parse().unwrap()).collect();
-        Self {
-            x: nums[0],
-            y: nums[1],
-            z: nums[2],
-        }
-    }
-}
-
 #[derive(Debug, Eq, PartialEq, Hash)]
 struct KeyPair(Vector3, Vector3);
 
@@ -102,7 +84,8 @@ fn create_distance_map(junctions: &[Vector3]) -> HashMap<KeyPair, i64> {
                 continue;
             }
 
-            let distance = junction.calc_euc_dist(target_junction);
+            // Squared is enough, we only need to sort by distance
+            let distance = junction.distance_squared(target_junction);
             distance_map.insert(keypair, distance);
         }
     }
@@ -110,14 +93,6 @@ fn create_distance_map(junctions: &[Vector3]) -> HashMap<KeyPair, i64> {
     distance_map
 }
 
-impl Vector3 {
-    fn calc_euc_dist(&self, target: &Vector3) -> i64 {
-        // Don't bother with sqrt here, this gives us enough info
-        // we don't need to know the actual distance to sort
-        (self.x - target.x).pow(2) + (self.y - target.y).pow(2) + (self.z - target.z).pow(2)
-    }
-}
-
 // You start with a hash map of single size circuits
 // You continue to union the circuits of each vector pair until you get 1000
 fn build_circuits_p1(junctions: &[Vector3], distances: &[(&KeyPair, &i64)]) -> i32 {
@@ -164,7 +139,7 @@ fn build_circuits_p2(junctions: &[Vector3], distances: &[(&KeyPair, &i64)]) -> i
 }
 
 pub fn solve(input: &str) -> SolutionPair {
-    let junctions: Vec<Vector3> = input.lines().map(Vector3::from).collect();
+    let junctions: Vec<Vector3> = input.lines().map(|line| line.parse().unwrap()).collect();
     let distance_map = create_distance_map(&junctions);
     let mut distances: Vec<_> = distance_map.iter().collect();
     distances.sort_by_key(|&(_, v)| v);
@@ -183,15 +158,13 @@ mod tests {
     fn test_example_input_day8_p1() {
         let input = "162,817,812\n57,618,57\n906,360,560\n592,479,940\n352,342,300\n466,668,158\n542,29,236\n431,825,988\n739,650,466\n52,470,668\n216,146,977\n819,987,18\n117,168,530\n805,96,715\n346,949,466\n970,615,88\n941,993,340\n862,61,35\n984,92,344\n425,690,689";
         let (p1, _) = solve(input);
-        let p1_result = format!("{p1}");
-        assert_eq!(p1_result, "40");
+        assert_eq!(p1, Solution::from(40));
     }
 
     #[test]
     fn test_example_input_day8_p2() {
         let input = "162,817,812\n57,618,57\n906,360,560\n592,479,940\n352,342,300\n466,668,158\n542,29,236\n431,825,988\n739,650,466\n52,470,668\n216,146,977\n819,987,18\n117,168,530\n805,96,715\n346,949,466\n970,615,88\n941,993,340\n862,61,35\n984,92,344\n425,690,689";
         let (_, p2) = solve(input);
-        let p2_result = format!("{p2}");
-        assert_eq!(p2_result, "25272");
+        assert_eq!(p2, Solution::from(25272));
     }
 }
@@ -1,33 +1,17 @@
 use std::cmp::{max, min};
 
-use crate::{Solution, SolutionPair};
+use crate::{Solution, SolutionPair, utils::pos::Pos};
 
 ///////////////////////////////////////////////////////////////////////////////
 
-#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
-struct Point {
-    x: i64,
-    y: i64,
-}
-
-impl From<&str> for Point {
-    fn from(value: &str) -> Self {
-        let num_strs = value.split_once(',').unwrap();
-        Self {
-            x: num_strs.0.parse().unwrap(),
-            y: num_strs.1.parse().unwrap(),
-        }
-    }
-}
-
-fn get_area(p1: &Point, p2: &Point) -> i64 {
+fn get_area(p1: &Pos, p2: &Pos) -> i64 {
     let width = (p1.x - p2.x).abs() + 1;
     let height = (p1.y - p2.y) + 1;
     width * height
 }
 
-fn calculate_all_areas(points: &[Point]) -> Vec<(Point, Point, i64)> {
-    let mut all_areas: Vec<(Point, Point, i64)> = Vec::new();
+fn calculate_all_areas(points: &[Pos]) -> Vec<(Pos, Pos, i64)> {
+    let mut all_areas: Vec<(Pos, Pos, i64)> = Vec::new();
     for (i, p1) in points.iter().enumerate() {
         for (j, p2) in points.iter().enumerate() {
             if i == j {
@@ -41,7 +25,7 @@ fn calculate_all_areas(points: &[Point]) -> Vec<(Point, Point, i64)> {
     all_areas
 }
 
-fn is_valid_rectangle(candidate: &(Point, Point, i64), edges: &[(Point, Point)]) -> bool {
+fn is_valid_rectangle(candidate: &(Pos, Pos, i64), edges: &[(Pos, Pos)]) -> bool {
     let min_y = min(candidate.0.y, candidate.1.y);
     let max_y = max(candidate.0.y, candidate.1.y);
     let min_x = min(candidate.0.x, candidate.1.x);
@@ -61,7 +45,7 @@ fn is_valid_rectangle(candidate: &(Point, Point, i64), edges: &[(Point, Point)])
     true
 }
 
-fn get_largest_rectange_p1(points: &[Point]) -> i64 {
+fn get_largest_rectange_p1(points: &[Pos]) -> i64 {
     let all_areas = calculate_all_areas(points);
     all_areas
         .iter()
@@ -70,7 +54,7 @@ fn get_largest_rectange_p1(points: &[Point]) -> i64 {
         .unwrap()
 }
 
-fn get_largest_rectangle_p2(candidates: &[(Point, Point, i64)], points: &[(Point, Point)]) -> i64 {
+fn get_largest_rectangle_p2(candidates: &[(Pos, Pos, i64)], points: &[(Pos, Pos)]) -> i64 {
     candidates
         .iter()
         .find(|candidate| is_valid_rectangle(candidate, points))
@@ -79,7 +63,7 @@ fn get_largest_rectangle_p2(candidates: &[(Point, Point, i64)], points: &[(Point
 }
 
 pub fn solve(input: &str) -> SolutionPair {
-    let red_tiles: Vec<Point> = input.lines().map(Point::from).collect();
+    let red_tiles: Vec<Pos> = input.lines().map(|line| line.parse().unwrap()).collect();
     let sol1 = get_largest_rectange_p1(&red_tiles);
 
     // Get all possible
@@ -87,7 +71,7 @@ pub fn solve(input: &str) -> SolutionPair {
     all_areas.sort_by_key(|a| -a.2);
 
     // Get all edges of the polygon
-    let mut edges: Vec<(Point, Point)> = red_tiles.windows(2).map(|w| (w[0], w[1])).collect();
+    let mut edges: Vec<(Pos, Pos)> = red_tiles.windows(2).map(|w| (w[0], w[1])).collect();
     edges.push((red_tiles[red_tiles.len() - 1], red_tiles[0]));
 
     let sol2 = get_largest_rectangle_p2(&all_areas, &edges);
@@ -103,22 +87,20 @@ mod tests {
     fn test_example_input_day9_p1() {
         let input = "7,1\n11,1\n11,7\n9,7\n9,5\n2,5\n2,3\n7,3";
         let (p1, _) = solve(input);
-        let p1_result = format!("{p1}");
-        assert_eq!(p1_result, "50");
+        assert_eq!(p1, Solution::from(50));
     }
 
     #[test]
     fn test_example_input_day9_p2() {
         let input = "7,1\n11,1\n11,7\n9,7\n9,5\n2,5\n2,3\n7,3";
         let (_, p2) = solve(input);
-        let p2_result = format!("{p2}");
-        assert_eq!(p2_result, "24");
+        assert_eq!(p2, Solution::from(24));
     }
 
     #[test]
     fn test_get_area() {
-        let p1 = Point { x: 2, y: 5 };
-        let p2 = Point { x: 9, y: 7 };
+        let p1 = Pos { x: 2, y: 5 };
+        let p2 = Pos { x: 9, y: 7 };
         let result = get_area(&p1, &p2);
         assert_eq!(result, 24);
     }
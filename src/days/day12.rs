@@ -4,9 +4,9 @@ use crate::{Solution, SolutionPair};
 
 pub fn solve(input: &str) -> SolutionPair {
     println!("Input: \n{input}");
-    let sol1 = 0;
-    let sol2 = 0;
-    (Solution::from(sol1), Solution::from(sol2))
+    let sol1 = Solution::unsolved("not started");
+    let sol2 = Solution::unsolved("not started");
+    (sol1, sol2)
 }
 
 #[cfg(test)]
@@ -17,7 +17,6 @@ mod tests {
     fn test_example_input_day12_p1() {
         let input = "";
         let (p1, _) = solve(input);
-        let p1_result = format!("{p1}");
-        assert_eq!(p1_result, "0");
+        assert!(p1.is_unsolved());
     }
 }
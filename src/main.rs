@@ -36,9 +36,14 @@ fn main() {
         let (p1, p2) = func(&input);
         let elapsed_ms = time.elapsed().as_nanos() as f64 / 1_000_000.0;
 
+        // Optional known answers, one line per part
+        let answers_path = format!("answers/day{}.txt", day);
+        let answers = read_to_string(answers_path).unwrap_or_default();
+        let mut expected = answers.lines();
+
         println!("\n=== Day {:02} ===", day);
-        println!("  · Part 1: {}", p1);
-        println!("  · Part 2: {}", p2);
+        print_part(1, &p1, expected.next());
+        print_part(2, &p2, expected.next());
         println!("  · Elapsed: {:.4} ms", elapsed_ms);
 
         runtime += elapsed_ms;
@@ -47,6 +52,26 @@ fn main() {
     println!("Total runtime: {:.4} ms", runtime);
 }
 
+fn print_part(part: u8, solution: &Solution, expected: Option<&str>) {
+    let expected = expected.map(|answer| {
+        let Ok(answer) = answer.parse::<Solution>();
+        answer
+    });
+    let check = match expected {
+        _ if solution.is_unsolved() => String::new(),
+        Some(answer) if answer == *solution => " ✓".to_string(),
+        Some(answer) => format!(" ✗ (expected {})", answer),
+        None => String::new(),
+    };
+    println!("  · Part {}: {}{}", part, solution, check);
+
+    if let Some(picture) = solution.picture() {
+        for line in picture.lines() {
+            println!("      {}", line);
+        }
+    }
+}
+
 fn get_day_solver(day: u8) -> fn(&str) -> SolutionPair {
     match day {
         1 => day01::solve,
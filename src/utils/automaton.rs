@@ -0,0 +1,215 @@
+use std::mem::swap;
+
+use super::grid::{Grid, Point};
+use super::pathfinding::Neighborhood;
+
+#[allow(dead_code)]
+impl<T: Copy + PartialEq> Grid<T> {
+    // one generation, every cell is replaced by `rule(grid, cell)`
+    // the rule always sees the previous generation, returns how many cells changed
+    // allocates a back buffer each call, an `Automaton` keeps one between generations
+    pub fn step<F>(&mut self, rule: F) -> usize
+    where
+        F: Fn(&Grid<T>, &Point<T>) -> T,
+    {
+        let mut back = self.data.clone();
+        self.step_into(&mut back, rule)
+    }
+
+    // keep stepping until a generation changes nothing, returns how many generations changed something
+    // only cells next to a change are looked at again, so `rule` must only depend on
+    // the cell and its neighbours in `neighborhood`
+    pub fn run_until_stable<F>(&mut self, neighborhood: Neighborhood, rule: F) -> usize
+    where
+        F: Fn(&Grid<T>, &Point<T>) -> T,
+    {
+        self.run(neighborhood, rule, None)
+    }
+
+    // same as `run_until_stable`, but returns the number of changed cells in each generation
+    pub fn run_until_stable_with_counts<F>(
+        &mut self,
+        neighborhood: Neighborhood,
+        rule: F,
+    ) -> Vec<usize>
+    where
+        F: Fn(&Grid<T>, &Point<T>) -> T,
+    {
+        let mut counts = Vec::new();
+        self.run(neighborhood, rule, Some(&mut counts));
+        counts
+    }
+
+    // writes the next generation into `back` and swaps it in
+    fn step_into<F>(&mut self, back: &mut Vec<T>, rule: F) -> usize
+    where
+        F: Fn(&Grid<T>, &Point<T>) -> T,
+    {
+        let mut changed = 0;
+        for (i, next) in back.iter_mut().enumerate() {
+            let point = self.point_at(i);
+            *next = rule(self, &point);
+            if *next != point.value {
+                changed += 1;
+            }
+        }
+
+        swap(&mut self.data, back);
+        changed
+    }
+
+    fn run<F>(
+        &mut self,
+        neighborhood: Neighborhood,
+        rule: F,
+        mut counts: Option<&mut Vec<usize>>,
+    ) -> usize
+    where
+        F: Fn(&Grid<T>, &Point<T>) -> T,
+    {
+        let mut generations = 0;
+        let mut worklist: Vec<usize> = (0..self.data.len()).collect();
+        let mut queued = vec![false; self.data.len()];
+
+        while !worklist.is_empty() {
+            // evaluate everything against the current generation before writing
+            let changes: Vec<(Point<T>, T)> = worklist
+                .drain(..)
+                .map(|i| self.point_at(i))
+                .filter_map(|point| {
+                    let value = rule(self, &point);
+                    (value != point.value).then_some((point, value))
+                })
+                .collect();
+
+            if changes.is_empty() {
+                break;
+            }
+            generations += 1;
+            if let Some(counts) = counts.as_mut() {
+                counts.push(changes.len());
+            }
+
+            for (point, value) in &changes {
+                self.set(point, *value);
+            }
+
+            queued.fill(false);
+            for (point, _) in &changes {
+                let around = neighborhood
+                    .directions()
+                    .iter()
+                    .filter_map(|direction| self.get_point_in_direction(point, direction));
+
+                for cell in std::iter::once(*point).chain(around) {
+                    let i = cell.row * self.row_size + cell.col;
+                    if !queued[i] {
+                        queued[i] = true;
+                        worklist.push(i);
+                    }
+                }
+            }
+        }
+
+        generations
+    }
+}
+
+// A grid stepped many generations, with a back buffer kept between them
+// so stepping doesn't allocate
+pub struct Automaton<T> {
+    grid: Grid<T>,
+    back: Vec<T>,
+}
+
+#[allow(dead_code)]
+impl<T: Copy + PartialEq> Automaton<T> {
+    pub fn new(grid: Grid<T>) -> Self {
+        let back = grid.data.clone();
+        Self { grid, back }
+    }
+
+    // the current generation
+    pub fn grid(&self) -> &Grid<T> {
+        &self.grid
+    }
+
+    pub fn into_grid(self) -> Grid<T> {
+        self.grid
+    }
+
+    // same as `Grid::step`, reusing the back buffer
+    pub fn step<F>(&mut self, rule: F) -> usize
+    where
+        F: Fn(&Grid<T>, &Point<T>) -> T,
+    {
+        self.grid.step_into(&mut self.back, rule)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // conway's game of life
+    fn life(grid: &Grid, point: &Point) -> u8 {
+        let alive: usize = grid.count_neighbors_of_value(point, b'#');
+        match (point.value, alive) {
+            (b'#', 2 | 3) | (b'.', 3) => b'#',
+            _ => b'.',
+        }
+    }
+
+    #[test]
+    fn test_step_blinker() {
+        let mut grid = Grid::new(".....\n..#..\n..#..\n..#..\n.....");
+        assert_eq!(grid.step(life), 4);
+        assert_eq!(grid.to_string(), ".....\n.....\n.###.\n.....\n.....\n");
+        grid.step(life);
+        assert_eq!(grid.to_string(), ".....\n..#..\n..#..\n..#..\n.....\n");
+    }
+
+    #[test]
+    fn test_step_on_wrapping_grid() {
+        // a glider moves one cell diagonally every 4 generations, so 24 brings it back around
+        let start = ".#....\n..#...\n###...\n......\n......\n......";
+        let mut automaton = Automaton::new(Grid::new(start).with_wrapping(true, true));
+        for _ in 0..24 {
+            automaton.step(life);
+        }
+        assert_eq!(
+            automaton.into_grid().to_string(),
+            Grid::new(start).to_string()
+        );
+    }
+
+    #[test]
+    fn test_run_until_stable_erosion() {
+        // peel off cells with fewer than 4 filled neighbours until nothing changes
+        let erode = |grid: &Grid, point: &Point| {
+            let filled: usize = grid.count_neighbors_of_value(point, b'#');
+            if point.value == b'#' && filled < 4 {
+                b'.'
+            } else {
+                point.value
+            }
+        };
+
+        let input = "###.\n###.\n###.\n...#";
+        let mut grid = Grid::new(input);
+        let changes = grid.run_until_stable_with_counts(Neighborhood::Eight, erode);
+        assert_eq!(changes, vec![4, 3, 3]);
+        assert_eq!(grid.to_string(), "....\n....\n....\n....\n");
+
+        let mut quiet = Grid::new(input);
+        assert_eq!(quiet.run_until_stable(Neighborhood::Eight, erode), 3);
+        assert_eq!(quiet, grid);
+
+        // same generations as stepping the whole grid each time
+        let mut stepped = Automaton::new(Grid::new(input));
+        let counts: Vec<usize> = std::iter::repeat_with(|| stepped.step(erode))
+            .take_while(|&changed| changed > 0)
+            .collect();
+        assert_eq!(counts, changes);
+    }
+}
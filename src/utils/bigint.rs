@@ -0,0 +1,542 @@
+use std::cmp::Ordering;
+use std::fmt::{self, Display};
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub};
+use std::str::FromStr;
+
+// Decimal chunk used when printing and parsing, the largest power of 10 that fits a limb
+const DECIMAL_BASE: u32 = 1_000_000_000;
+const DECIMAL_DIGITS: usize = 9;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseBigIntError;
+
+impl Display for ParseBigIntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid digit found in string")
+    }
+}
+
+// Arbitrary precision unsigned integer, stored as little endian base 2^32 limbs
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BigUint {
+    // Never has trailing zero limbs, so zero is an empty vec
+    limbs: Vec<u32>,
+}
+
+impl BigUint {
+    pub fn zero() -> Self {
+        Self { limbs: Vec::new() }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    fn from_limbs(mut limbs: Vec<u32>) -> Self {
+        while limbs.last() == Some(&0) {
+            limbs.pop();
+        }
+        Self { limbs }
+    }
+
+    fn add_small(&mut self, value: u32) {
+        let mut carry = value as u64;
+        for limb in self.limbs.iter_mut() {
+            if carry == 0 {
+                return;
+            }
+            let sum = *limb as u64 + carry;
+            *limb = sum as u32;
+            carry = sum >> 32;
+        }
+        if carry > 0 {
+            self.limbs.push(carry as u32);
+        }
+    }
+
+    fn mul_small(&mut self, value: u32) {
+        if value == 0 {
+            self.limbs.clear();
+            return;
+        }
+        let mut carry = 0u64;
+        for limb in self.limbs.iter_mut() {
+            let product = *limb as u64 * value as u64 + carry;
+            *limb = product as u32;
+            carry = product >> 32;
+        }
+        if carry > 0 {
+            self.limbs.push(carry as u32);
+        }
+    }
+
+    // Divides in place and returns the remainder
+    fn div_rem_small(&mut self, divisor: u32) -> u32 {
+        let mut remainder = 0u64;
+        for limb in self.limbs.iter_mut().rev() {
+            let current = (remainder << 32) | *limb as u64;
+            *limb = (current / divisor as u64) as u32;
+            remainder = current % divisor as u64;
+        }
+        *self = Self::from_limbs(std::mem::take(&mut self.limbs));
+        remainder as u32
+    }
+
+    // Caller guarantees self >= other
+    fn sub_assign_smaller(&mut self, other: &BigUint) {
+        let mut borrow = 0i64;
+        for (i, limb) in self.limbs.iter_mut().enumerate() {
+            let rhs = other.limbs.get(i).copied().unwrap_or(0) as i64;
+            let mut diff = *limb as i64 - rhs - borrow;
+            borrow = 0;
+            if diff < 0 {
+                diff += 1 << 32;
+                borrow = 1;
+            }
+            *limb = diff as u32;
+        }
+        *self = Self::from_limbs(std::mem::take(&mut self.limbs));
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn add(self, rhs: &BigUint) -> BigUint {
+        let len = self.limbs.len().max(rhs.limbs.len());
+        let mut limbs = Vec::with_capacity(len + 1);
+        let mut carry = 0u64;
+        for i in 0..len {
+            let lhs = self.limbs.get(i).copied().unwrap_or(0) as u64;
+            let rhs = rhs.limbs.get(i).copied().unwrap_or(0) as u64;
+            let sum = lhs + rhs + carry;
+            limbs.push(sum as u32);
+            carry = sum >> 32;
+        }
+        limbs.push(carry as u32);
+        BigUint::from_limbs(limbs)
+    }
+}
+
+impl Mul<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn mul(self, rhs: &BigUint) -> BigUint {
+        if self.is_zero() || rhs.is_zero() {
+            return BigUint::zero();
+        }
+
+        // Schoolbook multiplication, inputs here are small enough that it's fine
+        let mut limbs = vec![0u32; self.limbs.len() + rhs.limbs.len()];
+        for (i, &a) in self.limbs.iter().enumerate() {
+            let mut carry = 0u64;
+            for (j, &b) in rhs.limbs.iter().enumerate() {
+                let current = limbs[i + j] as u64 + a as u64 * b as u64 + carry;
+                limbs[i + j] = current as u32;
+                carry = current >> 32;
+            }
+            limbs[i + rhs.limbs.len()] = carry as u32;
+        }
+        BigUint::from_limbs(limbs)
+    }
+}
+
+impl Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return f.pad_integral(true, "", "0");
+        }
+
+        let mut value = self.clone();
+        let mut chunks: Vec<u32> = Vec::new();
+        while !value.is_zero() {
+            chunks.push(value.div_rem_small(DECIMAL_BASE));
+        }
+
+        let mut digits = chunks.pop().unwrap().to_string();
+        for chunk in chunks.iter().rev() {
+            digits.push_str(&format!("{chunk:0DECIMAL_DIGITS$}"));
+        }
+        f.pad_integral(true, "", &digits)
+    }
+}
+
+impl FromStr for BigUint {
+    type Err = ParseBigIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let digits = s.strip_prefix('+').unwrap_or(s);
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ParseBigIntError);
+        }
+
+        let mut value = BigUint::zero();
+        for chunk in digits.as_bytes().chunks(DECIMAL_DIGITS) {
+            let chunk_str = std::str::from_utf8(chunk).unwrap();
+            value.mul_small(10u32.pow(chunk.len() as u32));
+            value.add_small(chunk_str.parse().unwrap());
+        }
+        Ok(value)
+    }
+}
+
+impl From<u128> for BigUint {
+    fn from(value: u128) -> Self {
+        let limbs = (0..4).map(|i| (value >> (32 * i)) as u32).collect();
+        Self::from_limbs(limbs)
+    }
+}
+
+impl TryFrom<&BigUint> for u128 {
+    type Error = ();
+
+    fn try_from(value: &BigUint) -> Result<Self, Self::Error> {
+        if value.limbs.len() > 4 {
+            return Err(());
+        }
+        Ok(value
+            .limbs
+            .iter()
+            .enumerate()
+            .fold(0u128, |acc, (i, &limb)| acc | (limb as u128) << (32 * i)))
+    }
+}
+
+// Arbitrary precision signed integer, a sign and a `BigUint` magnitude
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BigInt {
+    // Zero is never negative, so derived equality holds
+    negative: bool,
+    magnitude: BigUint,
+}
+
+impl BigInt {
+    pub fn zero() -> Self {
+        Self::default()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub fn magnitude(&self) -> &BigUint {
+        &self.magnitude
+    }
+
+    fn from_parts(negative: bool, magnitude: BigUint) -> Self {
+        Self {
+            negative: negative && !magnitude.is_zero(),
+            magnitude,
+        }
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, false) => self.magnitude.cmp(&other.magnitude),
+            (true, true) => other.magnitude.cmp(&self.magnitude),
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Neg for BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt::from_parts(!self.negative, self.magnitude)
+    }
+}
+
+impl Add<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn add(self, rhs: &BigInt) -> BigInt {
+        if self.negative == rhs.negative {
+            return BigInt::from_parts(self.negative, &self.magnitude + &rhs.magnitude);
+        }
+
+        // Opposite signs, subtract the smaller magnitude from the larger one
+        let (larger, smaller) = if self.magnitude >= rhs.magnitude {
+            (self, rhs)
+        } else {
+            (rhs, self)
+        };
+        let mut magnitude = larger.magnitude.clone();
+        magnitude.sub_assign_smaller(&smaller.magnitude);
+        BigInt::from_parts(larger.negative, magnitude)
+    }
+}
+
+impl Sub<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn sub(self, rhs: &BigInt) -> BigInt {
+        self + &-rhs.clone()
+    }
+}
+
+impl Mul<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn mul(self, rhs: &BigInt) -> BigInt {
+        BigInt::from_parts(
+            self.negative != rhs.negative,
+            &self.magnitude * &rhs.magnitude,
+        )
+    }
+}
+
+// Owned operator forms forward to the borrowed ones
+macro_rules! forward_owned_ops {
+    ($type_:ident) => {
+        impl Add for $type_ {
+            type Output = $type_;
+
+            fn add(self, rhs: $type_) -> $type_ {
+                &self + &rhs
+            }
+        }
+
+        impl Mul for $type_ {
+            type Output = $type_;
+
+            fn mul(self, rhs: $type_) -> $type_ {
+                &self * &rhs
+            }
+        }
+
+        impl AddAssign<&$type_> for $type_ {
+            fn add_assign(&mut self, rhs: &$type_) {
+                *self = &*self + rhs;
+            }
+        }
+
+        impl AddAssign for $type_ {
+            fn add_assign(&mut self, rhs: $type_) {
+                *self = &*self + &rhs;
+            }
+        }
+
+        impl MulAssign<&$type_> for $type_ {
+            fn mul_assign(&mut self, rhs: &$type_) {
+                *self = &*self * rhs;
+            }
+        }
+
+        impl Sum for $type_ {
+            fn sum<I: Iterator<Item = $type_>>(iter: I) -> Self {
+                iter.fold($type_::zero(), |acc, x| acc + x)
+            }
+        }
+    };
+}
+
+forward_owned_ops!(BigUint);
+forward_owned_ops!(BigInt);
+
+impl Sub for BigInt {
+    type Output = BigInt;
+
+    fn sub(self, rhs: BigInt) -> BigInt {
+        &self - &rhs
+    }
+}
+
+impl Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad_integral(!self.negative, "", &self.magnitude.to_string())
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = ParseBigIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.strip_prefix('-') {
+            Some(digits) => Ok(BigInt::from_parts(true, digits.parse()?)),
+            None => Ok(BigInt::from_parts(false, s.parse()?)),
+        }
+    }
+}
+
+impl From<BigUint> for BigInt {
+    fn from(magnitude: BigUint) -> Self {
+        BigInt::from_parts(false, magnitude)
+    }
+}
+
+impl From<i128> for BigInt {
+    fn from(value: i128) -> Self {
+        BigInt::from_parts(value < 0, BigUint::from(value.unsigned_abs()))
+    }
+}
+
+impl From<u128> for BigInt {
+    fn from(value: u128) -> Self {
+        BigInt::from_parts(false, BigUint::from(value))
+    }
+}
+
+impl TryFrom<&BigInt> for i128 {
+    type Error = ();
+
+    fn try_from(value: &BigInt) -> Result<Self, Self::Error> {
+        let magnitude = u128::try_from(&value.magnitude)?;
+        if value.negative {
+            // i128::MIN has no positive counterpart, so check against its magnitude
+            if magnitude <= i128::MIN.unsigned_abs() {
+                Ok((magnitude as i128).wrapping_neg())
+            } else {
+                Err(())
+            }
+        } else {
+            i128::try_from(magnitude).map_err(|_| ())
+        }
+    }
+}
+
+impl TryFrom<&BigInt> for u128 {
+    type Error = ();
+
+    fn try_from(value: &BigInt) -> Result<Self, Self::Error> {
+        if value.negative {
+            return Err(());
+        }
+        u128::try_from(&value.magnitude)
+    }
+}
+
+macro_rules! impl_from_primitive {
+    ($type_:ident, $wide_:ident) => {
+        impl From<$type_> for BigUint {
+            fn from(value: $type_) -> Self {
+                BigUint::from(value as u128)
+            }
+        }
+
+        impl From<$type_> for BigInt {
+            fn from(value: $type_) -> Self {
+                BigInt::from(value as $wide_)
+            }
+        }
+    };
+    ($type_:ident) => {
+        impl From<$type_> for BigInt {
+            fn from(value: $type_) -> Self {
+                BigInt::from(value as i128)
+            }
+        }
+    };
+}
+
+impl_from_primitive!(i8);
+impl_from_primitive!(i16);
+impl_from_primitive!(i32);
+impl_from_primitive!(i64);
+impl_from_primitive!(isize);
+impl_from_primitive!(u8, u128);
+impl_from_primitive!(u16, u128);
+impl_from_primitive!(u32, u128);
+impl_from_primitive!(u64, u128);
+impl_from_primitive!(usize, u128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn big(value: &str) -> BigInt {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        for value in [
+            "0",
+            "7",
+            "-7",
+            "1000000000",
+            "123456789012345678901234567890123",
+        ] {
+            assert_eq!(big(value).to_string(), value);
+        }
+    }
+
+    #[test]
+    fn test_add_past_u128() {
+        let sum = BigInt::from(u128::MAX) + BigInt::from(1u8);
+        assert_eq!(sum.to_string(), "340282366920938463463374607431768211456");
+    }
+
+    #[test]
+    fn test_add_mixed_signs() {
+        assert_eq!(big("-5") + big("3"), big("-2"));
+        assert_eq!(big("5") + big("-5"), BigInt::zero());
+        assert_eq!(
+            big("-100000000000000000000") - big("-1"),
+            big("-99999999999999999999")
+        );
+    }
+
+    #[test]
+    fn test_mul() {
+        let product = BigInt::from(u64::MAX) * BigInt::from(u64::MAX);
+        assert_eq!(product, BigInt::from(u64::MAX as u128 * u64::MAX as u128));
+        assert_eq!(big("-3") * big("4"), big("-12"));
+        assert_eq!(big("-3") * BigInt::zero(), BigInt::zero());
+    }
+
+    #[test]
+    fn test_compare() {
+        assert!(big("-10") < big("-9"));
+        assert!(big("-1") < BigInt::zero());
+        assert!(big("18446744073709551616") > big("18446744073709551615"));
+    }
+
+    #[test]
+    fn test_sum() {
+        let total: BigInt = (0..4).map(|_| BigInt::from(u128::MAX)).sum();
+        assert_eq!(
+            total.to_string(),
+            "1361129467683753853853498429727072845820"
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!("".parse::<BigInt>(), Err(ParseBigIntError));
+        assert_eq!("12a".parse::<BigInt>(), Err(ParseBigIntError));
+        assert_eq!("--1".parse::<BigInt>(), Err(ParseBigIntError));
+    }
+
+    #[test]
+    fn test_try_into_primitives() {
+        assert_eq!(i128::try_from(&BigInt::from(i128::MIN)), Ok(i128::MIN));
+        assert_eq!(u128::try_from(&big("-1")), Err(()));
+        assert_eq!(i128::try_from(&BigInt::from(u128::MAX)), Err(()));
+    }
+}
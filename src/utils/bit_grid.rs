@@ -0,0 +1,302 @@
+use std::fmt::{self, Display};
+use std::ops::{BitAnd, BitOr, BitXor, Not};
+
+use super::grid::{ALL_DIRECTIONS, Directions, Grid, Point};
+
+// Grid of booleans packed 64 cells to a word, every row starts on a new word
+// Bits past the end of a row are always zero
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    words: Vec<u64>,
+    words_per_row: usize,
+    num_rows: usize,
+    row_size: usize,
+}
+
+#[allow(dead_code)]
+impl BitGrid {
+    pub fn new(num_rows: usize, row_size: usize) -> Self {
+        let words_per_row = row_size.div_ceil(64);
+        Self {
+            words: vec![0; num_rows * words_per_row],
+            words_per_row,
+            num_rows,
+            row_size,
+        }
+    }
+
+    // cells of `grid` where `predicate` holds are set
+    pub fn from_grid<T, F>(grid: &Grid<T>, predicate: F) -> Self
+    where
+        T: Copy,
+        F: Fn(&Point<T>) -> bool,
+    {
+        let mut bits = Self::new(grid.num_rows(), grid.row_size);
+        for point in grid.iter_2d().filter(|point| predicate(point)) {
+            bits.set(point.row, point.col, true);
+        }
+        bits
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    pub fn row_size(&self) -> usize {
+        self.row_size
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> bool {
+        if row >= self.num_rows || col >= self.row_size {
+            return false;
+        }
+        let word = self.words[row * self.words_per_row + col / 64];
+        word >> (col % 64) & 1 == 1
+    }
+
+    pub fn set(&mut self, row: usize, col: usize, value: bool) {
+        assert!(
+            row < self.num_rows && col < self.row_size,
+            "({row}, {col}) is out of bounds"
+        );
+        let word = &mut self.words[row * self.words_per_row + col / 64];
+        let bit = 1 << (col % 64);
+        if value {
+            *word |= bit;
+        } else {
+            *word &= !bit;
+        }
+    }
+
+    // number of set cells
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    // (row, col) of every set cell, row by row
+    pub fn iter_ones(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (0..self.num_rows).flat_map(move |row| {
+            (0..self.row_size).filter_map(move |col| self.get(row, col).then_some((row, col)))
+        })
+    }
+
+    // every cell takes the value of its neighbour in `direction`, off the edge counts as unset
+    pub fn shifted(&self, direction: &Directions) -> Self {
+        let (dr, dc) = direction.delta();
+        let mut shifted = Self::new(self.num_rows, self.row_size);
+
+        for row in 0..self.num_rows {
+            let Some(source) = row.checked_add_signed(dr).filter(|&r| r < self.num_rows) else {
+                continue;
+            };
+            let from = self.row_words(source);
+            let to = &mut shifted.words[row * self.words_per_row..][..self.words_per_row];
+
+            for (i, word) in to.iter_mut().enumerate() {
+                *word = match dc {
+                    // the neighbour on the right is one bit higher
+                    1 => from[i] >> 1 | from.get(i + 1).map_or(0, |next| next << 63),
+                    -1 => from[i] << 1 | if i > 0 { from[i - 1] >> 63 } else { 0 },
+                    _ => from[i],
+                };
+            }
+        }
+
+        shifted.clear_padding();
+        shifted
+    }
+
+    // cells with fewer than `n` set neighbours out of the 8 around them
+    // counts for every cell in a word at once, as a 4 bit number spread over 4 words
+    pub fn neighbors_fewer_than(&self, n: u32) -> Self {
+        if n > 8 {
+            return !&Self::new(self.num_rows, self.row_size);
+        }
+
+        let mut planes = vec![[0u64; 4]; self.words.len()];
+        for direction in &ALL_DIRECTIONS {
+            let neighbor = self.shifted(direction);
+            for (counter, &word) in planes.iter_mut().zip(&neighbor.words) {
+                // ripple carry add of one bit into every cell's counter
+                let mut carry = word;
+                for plane in counter.iter_mut() {
+                    let sum = *plane ^ carry;
+                    carry &= *plane;
+                    *plane = sum;
+                }
+            }
+        }
+
+        let mut result = Self::new(self.num_rows, self.row_size);
+        for (word, counter) in result.words.iter_mut().zip(&planes) {
+            // compare each counter to `n` from the highest bit down
+            let mut less = 0;
+            let mut equal = !0;
+            for (bit, plane) in counter.iter().enumerate().rev() {
+                if n >> bit & 1 == 1 {
+                    less |= equal & !plane;
+                    equal &= plane;
+                } else {
+                    equal &= !plane;
+                }
+            }
+            *word = less;
+        }
+
+        result.clear_padding();
+        result
+    }
+
+    fn row_words(&self, row: usize) -> &[u64] {
+        &self.words[row * self.words_per_row..][..self.words_per_row]
+    }
+
+    // zero the unused bits at the end of every row
+    fn clear_padding(&mut self) {
+        let used = self.row_size % 64;
+        if used == 0 {
+            return;
+        }
+        let mask = (1u64 << used) - 1;
+        for row in 0..self.num_rows {
+            self.words[(row + 1) * self.words_per_row - 1] &= mask;
+        }
+    }
+
+    fn zip_with(&self, other: &Self, op: impl Fn(u64, u64) -> u64) -> Self {
+        assert_eq!(
+            (self.num_rows, self.row_size),
+            (other.num_rows, other.row_size),
+            "bit grids have different shapes"
+        );
+        let words = self
+            .words
+            .iter()
+            .zip(&other.words)
+            .map(|(&a, &b)| op(a, b))
+            .collect();
+        Self { words, ..*self }
+    }
+}
+
+impl BitAnd for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitand(self, rhs: &BitGrid) -> BitGrid {
+        self.zip_with(rhs, |a, b| a & b)
+    }
+}
+
+impl BitOr for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitor(self, rhs: &BitGrid) -> BitGrid {
+        self.zip_with(rhs, |a, b| a | b)
+    }
+}
+
+impl BitXor for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitxor(self, rhs: &BitGrid) -> BitGrid {
+        self.zip_with(rhs, |a, b| a ^ b)
+    }
+}
+
+impl Not for &BitGrid {
+    type Output = BitGrid;
+
+    fn not(self) -> BitGrid {
+        let mut inverted = BitGrid {
+            words: self.words.iter().map(|word| !word).collect(),
+            ..*self
+        };
+        inverted.clear_padding();
+        inverted
+    }
+}
+
+// `#` for set cells and `.` for the rest, like the puzzle inputs
+impl Display for BitGrid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in 0..self.num_rows {
+            for col in 0..self.row_size {
+                write!(f, "{}", if self.get(row, col) { '#' } else { '.' })?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &str) -> BitGrid {
+        BitGrid::from_grid(&Grid::new(input), |point| point.value == b'#')
+    }
+
+    #[test]
+    fn test_shifted() {
+        let bits = parse("#..\n.#.\n..#");
+        assert_eq!(
+            bits.shifted(&Directions::Right).to_string(),
+            "...\n#..\n.#.\n"
+        );
+        assert_eq!(
+            bits.shifted(&Directions::Left).to_string(),
+            ".#.\n..#\n...\n"
+        );
+        assert_eq!(
+            bits.shifted(&Directions::Bottom).to_string(),
+            ".#.\n..#\n...\n"
+        );
+        assert_eq!(
+            bits.shifted(&Directions::TopLeft).to_string(),
+            "...\n.#.\n..#\n"
+        );
+    }
+
+    #[test]
+    fn test_shifted_across_words() {
+        let mut bits = BitGrid::new(1, 130);
+        bits.set(0, 64, true);
+        bits.set(0, 129, true);
+        let right = bits.shifted(&Directions::Right);
+        assert_eq!(
+            right.iter_ones().collect::<Vec<_>>(),
+            vec![(0, 63), (0, 128)]
+        );
+        let left = bits.shifted(&Directions::Left);
+        // the last cell falls off the end of the row
+        assert_eq!(left.iter_ones().collect::<Vec<_>>(), vec![(0, 65)]);
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = parse("##.\n...");
+        let b = parse(".##\n..#");
+        assert_eq!((&a & &b).to_string(), ".#.\n...\n");
+        assert_eq!((&a | &b).to_string(), "###\n..#\n");
+        assert_eq!((&a ^ &b).to_string(), "#.#\n..#\n");
+        assert_eq!((!&a).to_string(), "..#\n###\n");
+        assert_eq!((!&a).count_ones(), 4);
+    }
+
+    #[test]
+    fn test_neighbors_fewer_than_matches_counting() {
+        let grid = Grid::new("..##.####.\n###.#.#.##\n#####.#.##\n#.####..#.\n##.####.##");
+        let bits = BitGrid::from_grid(&grid, |point| point.value == b'#');
+        for n in 0..=9 {
+            let fewer = bits.neighbors_fewer_than(n);
+            for point in grid.iter_2d() {
+                let count: u32 = grid.count_neighbors_of_value(&point, b'#');
+                assert_eq!(fewer.get(point.row, point.col), count < n);
+            }
+        }
+    }
+}
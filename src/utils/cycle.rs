@@ -0,0 +1,250 @@
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+use super::grid::Grid;
+
+// A sequence that repeats forever once it reaches `start`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    // index of the first state that is part of the cycle
+    pub start: usize,
+    pub length: usize,
+}
+
+#[allow(dead_code)]
+impl Cycle {
+    // index of an earlier state that is the same as state `n`
+    pub fn equivalent_step(&self, n: usize) -> usize {
+        if n < self.start {
+            return n;
+        }
+        self.start + (n - self.start) % self.length
+    }
+}
+
+// Floyd's tortoise and hare, `next` must be a pure function of the state
+#[allow(dead_code)]
+pub fn floyd<S, F>(initial: S, mut next: F) -> Cycle
+where
+    S: Clone + PartialEq,
+    F: FnMut(&S) -> S,
+{
+    let mut tortoise = next(&initial);
+    let mut hare = next(&tortoise);
+    while tortoise != hare {
+        tortoise = next(&tortoise);
+        hare = next(&hare);
+        hare = next(&hare);
+    }
+
+    // the distance from the start to the meeting point is a multiple of the cycle length
+    let mut start = 0;
+    tortoise = initial;
+    while tortoise != hare {
+        tortoise = next(&tortoise);
+        hare = next(&hare);
+        start += 1;
+    }
+
+    let mut length = 1;
+    hare = next(&tortoise);
+    while tortoise != hare {
+        hare = next(&hare);
+        length += 1;
+    }
+
+    Cycle { start, length }
+}
+
+// Brent's algorithm, fewer calls to `next` than floyd
+#[allow(dead_code)]
+pub fn brent<S, F>(initial: S, mut next: F) -> Cycle
+where
+    S: Clone + PartialEq,
+    F: FnMut(&S) -> S,
+{
+    // find the length by searching in powers of two
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = next(&initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = next(&hare);
+        length += 1;
+    }
+
+    // hare starts `length` ahead, they meet at the start of the cycle
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..length {
+        hare = next(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = next(&tortoise);
+        hare = next(&hare);
+        start += 1;
+    }
+
+    Cycle { start, length }
+}
+
+// Remembers every state seen, needs memory but only steps through the sequence once
+// Also hands back the states so the one at step N can be looked up without rerunning
+#[allow(dead_code)]
+pub fn find_with_history<S, F>(initial: S, mut next: F) -> (Cycle, Vec<S>)
+where
+    S: Clone + Hash + Eq,
+    F: FnMut(&S) -> S,
+{
+    let mut seen: HashMap<S, usize> = HashMap::new();
+    let mut history = Vec::new();
+    let mut state = initial;
+
+    loop {
+        if let Some(&start) = seen.get(&state) {
+            let length = history.len() - start;
+            return (Cycle { start, length }, history);
+        }
+
+        seen.insert(state.clone(), history.len());
+        let following = next(&state);
+        history.push(state);
+        state = following;
+    }
+}
+
+// state at step `n` of a sequence that is known to cycle, e.g. n = 1_000_000_000
+#[allow(dead_code)]
+pub fn state_at<S, F>(initial: S, next: F, n: usize) -> S
+where
+    S: Clone + Hash + Eq,
+    F: FnMut(&S) -> S,
+{
+    let (cycle, mut history) = find_with_history(initial, next);
+    history.swap_remove(cycle.equivalent_step(n))
+}
+
+// FNV-1a, fast for short keys and good enough for telling grid states apart
+#[derive(Clone, Copy, Debug)]
+pub struct FnvHasher(u64);
+
+impl Default for FnvHasher {
+    fn default() -> Self {
+        Self(0xcbf29ce484222325)
+    }
+}
+
+impl Hasher for FnvHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= byte as u64;
+            self.0 = self.0.wrapping_mul(0x100000001b3);
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl<T: Hash> Grid<T> {
+    // cheap 64 bit hash of the cells, for storing seen states instead of whole grids
+    pub fn fingerprint(&self) -> u64 {
+        let mut hasher = FnvHasher::default();
+        self.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 1 -> 2 -> 3 -> 4 -> 5 -> 6 -> 3 -> ..., so the cycle starts at index 2 and has length 4
+    fn next(n: &u32) -> u32 {
+        if *n == 6 { 3 } else { n + 1 }
+    }
+
+    #[test]
+    fn test_floyd_and_brent_agree() {
+        let expected = Cycle {
+            start: 2,
+            length: 4,
+        };
+        assert_eq!(floyd(1, next), expected);
+        assert_eq!(brent(1, next), expected);
+        assert_eq!(find_with_history(1, next).0, expected);
+    }
+
+    #[test]
+    fn test_cycle_from_the_first_state() {
+        let expected = Cycle {
+            start: 0,
+            length: 4,
+        };
+        assert_eq!(floyd(3, next), expected);
+        assert_eq!(brent(3, next), expected);
+    }
+
+    #[test]
+    fn test_stateful_step() {
+        // every detector takes a closure that keeps its own state
+        let mut calls = 0;
+        let counting = |n: &u32| {
+            calls += 1;
+            next(n)
+        };
+        assert_eq!(brent(1, counting).length, 4);
+        assert!(calls > 0);
+
+        let mut calls = 0;
+        let counting = |n: &u32| {
+            calls += 1;
+            next(n)
+        };
+        assert_eq!(floyd(1, counting).start, 2);
+        assert!(calls > 0);
+    }
+
+    #[test]
+    fn test_extrapolate_to_step_n() {
+        // 1 2 3 4 5 6 3 4 5 6 ...
+        assert_eq!(state_at(1, next, 1), 2);
+        assert_eq!(state_at(1, next, 6), 3);
+        assert_eq!(state_at(1, next, 1_000_000_000), 5);
+    }
+
+    #[test]
+    fn test_grid_fingerprint() {
+        let grid = Grid::new("#.\n.#");
+        assert_eq!(grid.fingerprint(), Grid::new("#.\n.#").fingerprint());
+        assert_ne!(grid.fingerprint(), grid.rotate_cw().fingerprint());
+        // same cells in a different shape
+        assert_ne!(grid.fingerprint(), Grid::new("#..#").fingerprint());
+    }
+
+    #[test]
+    fn test_grid_states_cycle() {
+        // rotating a quarter turn comes back after 4 steps
+        let grid = Grid::new("#..\n...\n...");
+        let (cycle, _) = find_with_history(grid.clone(), |g| g.rotate_cw());
+        assert_eq!(
+            cycle,
+            Cycle {
+                start: 0,
+                length: 4
+            }
+        );
+        assert_eq!(
+            state_at(grid.clone(), |g| g.rotate_cw(), 1_000_000_001),
+            grid.rotate_cw()
+        );
+    }
+}
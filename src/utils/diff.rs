@@ -0,0 +1,166 @@
+use std::collections::HashSet;
+use std::fmt::{self, Display};
+
+use super::grid::Grid;
+use super::render::write_styled;
+
+// red background on cells that differ
+const CHANGED_STYLE: &str = "\x1b[1;41m";
+
+// A cell that differs between two grids
+// None when the cell is outside one of the grids because their shapes differ
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CellChange<T> {
+    pub row: usize,
+    pub col: usize,
+    pub old: Option<T>,
+    pub new: Option<T>,
+}
+
+#[allow(dead_code)]
+impl<T: Copy + PartialEq> Grid<T> {
+    // every cell that differs from `other`, row by row over the area covered by either grid
+    pub fn diff(&self, other: &Grid<T>) -> Vec<CellChange<T>> {
+        let num_rows = self.num_rows().max(other.num_rows());
+        let row_size = self.row_size.max(other.row_size);
+
+        (0..num_rows)
+            .flat_map(|row| (0..row_size).map(move |col| (row, col)))
+            .filter_map(|(row, col)| {
+                let old = self.get(row, col).copied();
+                let new = other.get(row, col).copied();
+                (old != new).then_some(CellChange { row, col, old, new })
+            })
+            .collect()
+    }
+}
+
+// Two grids next to each other with the differences highlighted
+// Without colour a third panel marks the changed cells with `X`
+pub struct SideBySide<'a> {
+    left: &'a Grid,
+    right: &'a Grid,
+    color: bool,
+}
+
+#[allow(dead_code)]
+impl Grid {
+    pub fn side_by_side<'a>(&'a self, other: &'a Grid) -> SideBySide<'a> {
+        SideBySide {
+            left: self,
+            right: other,
+            color: true,
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl SideBySide<'_> {
+    // when off a third panel marks the changed cells instead of highlighting them
+    pub fn color(mut self, on: bool) -> Self {
+        self.color = on;
+        self
+    }
+
+    // one row of `grid`, highlighting the cells in `changed`
+    fn write_panel(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        grid: &Grid,
+        changed: &HashSet<(usize, usize)>,
+        row: usize,
+    ) -> fmt::Result {
+        let row_size = self.left.row_size.max(self.right.row_size);
+        for col in 0..row_size {
+            let glyph = grid.get(row, col).map_or(' ', |&byte| byte as char);
+            let style = changed.contains(&(row, col)).then_some(CHANGED_STYLE);
+            write_styled(f, glyph, style, self.color)?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for SideBySide<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (left, right) = (self.left, self.right);
+        let row_size = left.row_size.max(right.row_size);
+        let changed: HashSet<(usize, usize)> = left
+            .diff(right)
+            .iter()
+            .map(|change| (change.row, change.col))
+            .collect();
+
+        for row in 0..left.num_rows().max(right.num_rows()) {
+            self.write_panel(f, left, &changed, row)?;
+            write!(f, " | ")?;
+            self.write_panel(f, right, &changed, row)?;
+
+            if !self.color {
+                write!(f, " | ")?;
+                for col in 0..row_size {
+                    let marker = if changed.contains(&(row, col)) {
+                        'X'
+                    } else {
+                        '.'
+                    };
+                    write!(f, "{marker}")?;
+                }
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff() {
+        let before = Grid::new("..@\n.@.");
+        let after = Grid::new("..@\n..#");
+        assert_eq!(
+            before.diff(&after),
+            vec![
+                CellChange {
+                    row: 1,
+                    col: 1,
+                    old: Some(b'@'),
+                    new: Some(b'.'),
+                },
+                CellChange {
+                    row: 1,
+                    col: 2,
+                    old: Some(b'.'),
+                    new: Some(b'#'),
+                },
+            ]
+        );
+        assert!(before.diff(&before.clone()).is_empty());
+    }
+
+    #[test]
+    fn test_diff_different_shapes() {
+        let small = Grid::new("ab");
+        let large = Grid::new("ab\ncd");
+        let changes = small.diff(&large);
+        assert_eq!(changes.len(), 2);
+        assert_eq!((changes[0].old, changes[0].new), (None, Some(b'c')));
+    }
+
+    #[test]
+    fn test_side_by_side_plain() {
+        let before = Grid::new("#..\n.#.");
+        let after = Grid::new("#..\n..#");
+        let rendered = before.side_by_side(&after).color(false).to_string();
+        assert_eq!(rendered, "#.. | #.. | ...\n.#. | ..# | .XX\n");
+    }
+
+    #[test]
+    fn test_side_by_side_colour() {
+        let rendered = Grid::new("ab").side_by_side(&Grid::new("ac")).to_string();
+        assert_eq!(rendered, "a\x1b[1;41mb\x1b[0m | a\x1b[1;41mc\x1b[0m\n");
+    }
+}
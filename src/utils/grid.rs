@@ -1,8 +1,15 @@
 use core::fmt;
-use std::{fmt::Display, str::from_utf8};
+use std::{
+    fmt::Display,
+    hash::{Hash, Hasher},
+    ops::{Index, IndexMut},
+    str::from_utf8,
+};
 
-#[derive(Clone, Copy, Debug)]
-#[allow(dead_code)]
+use super::pos::Pos;
+
+// Ordered clockwise from Top, which `rotate` relies on
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum Directions {
     Top,
     TopRight,
@@ -14,7 +21,6 @@ pub enum Directions {
     TopLeft,
 }
 
-#[allow(dead_code)]
 pub const ALL_DIRECTIONS: [Directions; 8] = [
     Directions::Top,
     Directions::TopRight,
@@ -26,23 +32,160 @@ pub const ALL_DIRECTIONS: [Directions; 8] = [
     Directions::TopLeft,
 ];
 
+pub const CARDINAL_DIRECTIONS: [Directions; 4] = [
+    Directions::Top,
+    Directions::Right,
+    Directions::Bottom,
+    Directions::Left,
+];
+
+#[allow(dead_code)]
+impl Directions {
+    // (row, col) offset of one step
+    pub fn delta(&self) -> (isize, isize) {
+        match self {
+            Directions::Top => (-1, 0),
+            Directions::TopRight => (-1, 1),
+            Directions::Right => (0, 1),
+            Directions::BottomRight => (1, 1),
+            Directions::Bottom => (1, 0),
+            Directions::BottomLeft => (1, -1),
+            Directions::Left => (0, -1),
+            Directions::TopLeft => (-1, -1),
+        }
+    }
+
+    // turn clockwise by `steps` eighths of a turn, negative turns counter clockwise
+    pub fn rotate(&self, steps: i32) -> Self {
+        let index = (*self as i32 + steps).rem_euclid(8);
+        ALL_DIRECTIONS[index as usize]
+    }
+
+    // quarter turn clockwise
+    pub fn turn_right(&self) -> Self {
+        self.rotate(2)
+    }
+
+    // quarter turn counter clockwise
+    pub fn turn_left(&self) -> Self {
+        self.rotate(-2)
+    }
+
+    pub fn opposite(&self) -> Self {
+        self.rotate(4)
+    }
+
+    pub fn is_cardinal(&self) -> bool {
+        (*self as usize).is_multiple_of(2)
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 #[allow(dead_code)]
-pub struct Point {
+pub struct Point<T = u8> {
     pub row: usize,
     pub col: usize,
-    pub value: u8,
+    pub value: T,
 }
 
-#[derive(Clone)]
-pub struct Grid {
-    pub data: Vec<u8>,
+// How rows of different lengths are handled when building a grid
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[allow(dead_code)]
+pub enum Padding {
+    // every row must be the same length
+    Strict,
+    // short rows are padded up to the longest row with this byte
+    Fill(u8),
+    // trailing whitespace is dropped, then every row must be the same length
+    TrimTrailing,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum GridError {
+    RaggedRow {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl Display for GridError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GridError::RaggedRow {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {line} has {found} cells, expected {expected} like the first line"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for GridError {}
+
+// Which edges wrap around to the opposite side when stepping off the grid
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Wrapping {
+    // stepping off the left or right edge comes back on the other side
+    pub horizontal: bool,
+    // stepping off the top or bottom edge comes back on the other side
+    pub vertical: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct Grid<T = u8> {
+    pub data: Vec<T>,
     pub row_size: usize,
+    pub wrapping: Wrapping,
 }
 
 #[allow(dead_code)]
 impl Grid {
     pub fn new(input: &str) -> Self {
+        Self::parse_with(input, |b| b)
+    }
+
+    // like new, but errors on rows of different lengths instead of concatenating them
+    pub fn try_new(input: &str) -> Result<Self, GridError> {
+        Self::try_parse_with(input, Padding::Strict, |b| b)
+    }
+
+    pub fn try_new_with(input: &str, padding: Padding) -> Result<Self, GridError> {
+        Self::try_parse_with(input, padding, |b| b)
+    }
+
+    pub fn _as_bytes(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+#[allow(dead_code)]
+impl<T> Grid<T> {
+    pub fn from_vec(data: Vec<T>, row_size: usize) -> Self {
+        Self {
+            data,
+            row_size,
+            wrapping: Wrapping::default(),
+        }
+    }
+
+    // toroidal addressing, stepping off a wrapping edge comes back on the other side
+    pub fn with_wrapping(mut self, horizontal: bool, vertical: bool) -> Self {
+        self.wrapping = Wrapping {
+            horizontal,
+            vertical,
+        };
+        self
+    }
+
+    // build a grid of typed cells, mapping each input byte
+    pub fn parse_with<F>(input: &str, mut parse_cell: F) -> Self
+    where
+        F: FnMut(u8) -> T,
+    {
         let num_new_lines = input.bytes().filter(|&b| b == b'\n').count();
 
         let capacity = input.len() - num_new_lines;
@@ -56,14 +199,57 @@ impl Grid {
                 row_size = bytes.len();
             }
 
-            data.extend_from_slice(bytes);
+            data.extend(bytes.iter().map(|&b| parse_cell(b)));
+        }
+
+        Self::from_vec(data, row_size)
+    }
+
+    pub fn try_parse_with<F>(
+        input: &str,
+        padding: Padding,
+        mut parse_cell: F,
+    ) -> Result<Self, GridError>
+    where
+        F: FnMut(u8) -> T,
+    {
+        let lines: Vec<&[u8]> = input
+            .lines()
+            .map(|line| match padding {
+                Padding::TrimTrailing => line.trim_end().as_bytes(),
+                _ => line.as_bytes(),
+            })
+            .collect();
+
+        let row_size = match padding {
+            Padding::Fill(_) => lines.iter().map(|line| line.len()).max().unwrap_or(0),
+            _ => lines.first().map(|line| line.len()).unwrap_or(0),
+        };
+
+        let mut data = Vec::with_capacity(row_size * lines.len());
+        for (i, line) in lines.iter().enumerate() {
+            if line.len() != row_size {
+                let Padding::Fill(fill) = padding else {
+                    return Err(GridError::RaggedRow {
+                        line: i + 1,
+                        expected: row_size,
+                        found: line.len(),
+                    });
+                };
+
+                let fill = std::iter::repeat_n(fill, row_size - line.len());
+                data.extend(line.iter().copied().chain(fill).map(&mut parse_cell));
+                continue;
+            }
+
+            data.extend(line.iter().map(|&b| parse_cell(b)));
         }
 
-        Self { data, row_size }
+        Ok(Self::from_vec(data, row_size))
     }
 
-    // get when row size is constant
-    pub fn get(&self, row: usize, col: usize) -> Option<&u8> {
+    // index into data when row size is constant
+    fn index_of(&self, row: usize, col: usize) -> Option<usize> {
         let row_offset = self.row_size * row;
 
         // Check for out of bounds
@@ -74,125 +260,158 @@ impl Grid {
             return None;
         }
 
-        Some(&self.data[row_offset + col])
+        Some(row_offset + col)
     }
 
-    pub fn _as_bytes(&self) -> &[u8] {
-        &self.data
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        self.index_of(row, col).map(|i| &self.data[i])
     }
 
-    pub fn iter_rows(&self) -> impl Iterator<Item = &[u8]> {
-        self.data.chunks(self.row_size)
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        self.index_of(row, col).map(|i| &mut self.data[i])
     }
 
-    pub fn iter_2d(&self) -> impl Iterator<Item = Point> {
-        self.data.iter().enumerate().map(move |(i, value)| {
-            let row = i / self.row_size;
-            let col = i % self.row_size;
-            Point {
-                row,
-                col,
-                value: *value,
+    // replace the value at the point's position, returning the old value
+    pub fn set(&mut self, point: &Point<T>, value: T) -> Option<T> {
+        self.get_mut(point.row, point.col)
+            .map(|cell| std::mem::replace(cell, value))
+    }
+
+    // swap the values at two positions, false if either is out of bounds
+    pub fn swap(&mut self, a: &Point<T>, b: &Point<T>) -> bool {
+        match (self.index_of(a.row, a.col), self.index_of(b.row, b.col)) {
+            (Some(i), Some(j)) => {
+                self.data.swap(i, j);
+                true
             }
-        })
+            _ => false,
+        }
     }
 
-    pub fn get_point_in_direction(&self, point: &Point, direction: &Directions) -> Option<Point> {
-        // Map each direction to its row/col offset
-        let (dr, dc) = match direction {
-            Directions::Top => (-1, 0),
-            Directions::TopRight => (-1, 1),
-            Directions::Right => (0, 1),
-            Directions::BottomRight => (1, 1),
-            Directions::Bottom => (1, 0),
-            Directions::BottomLeft => (1, -1),
-            Directions::Left => (0, -1),
-            Directions::TopLeft => (-1, -1),
-        };
+    pub fn num_rows(&self) -> usize {
+        if self.row_size == 0 {
+            return 0;
+        }
+        self.data.len() / self.row_size
+    }
 
-        // Use checked_add/sub to avoid negative indices
-        let new_row = (point.row as isize).checked_add(dr)?;
-        let new_col = (point.col as isize).checked_add(dc)?;
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> {
+        self.data.chunks(self.row_size)
+    }
+
+    pub fn row(&self, row: usize) -> Option<&[T]> {
+        let start = self.index_of(row, 0)?;
+        Some(&self.data[start..start + self.row_size])
+    }
 
-        // Guard against negative
-        if new_row < 0 || new_col < 0 {
+    // cells of a column, top to bottom
+    pub fn col(&self, col: usize) -> Option<impl Iterator<Item = &T>> {
+        if col >= self.row_size {
             return None;
         }
+        Some(self.data.iter().skip(col).step_by(self.row_size))
+    }
 
-        let new_row = new_row as usize;
-        let new_col = new_col as usize;
-
-        // Use get and map the result
-        self.get(new_row, new_col).map(|val| Point {
-            row: new_row,
-            col: new_col,
-            value: *val,
-        })
+    pub fn iter_cols(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.row_size).map(|col| self.data.iter().skip(col).step_by(self.row_size))
     }
 
-    pub unsafe fn get_point_in_direction_ptr(
-        &mut self,
-        point: &Point,
-        direction: &Directions,
-    ) -> Option<&mut u8> {
-        // Map each direction to its row/col offset
-        let (dr, dc) = match direction {
-            Directions::Top => (-1, 0),
-            Directions::TopRight => (-1, 1),
-            Directions::Right => (0, 1),
-            Directions::BottomRight => (1, 1),
-            Directions::Bottom => (1, 0),
-            Directions::BottomLeft => (1, -1),
-            Directions::Left => (0, -1),
-            Directions::TopLeft => (-1, -1),
-        };
+    // render each cell as a single char
+    pub fn display_with<F>(&self, render_cell: F) -> impl Display
+    where
+        F: Fn(&T) -> char,
+    {
+        GridDisplay {
+            grid: self,
+            render_cell,
+        }
+    }
+}
 
-        // Use checked_add/sub to avoid negative indices
-        let new_row = (point.row as isize).checked_add(dr)?;
-        let new_col = (point.col as isize).checked_add(dc)?;
+#[allow(dead_code)]
+impl<T: Copy> Grid<T> {
+    pub fn iter_2d(&self) -> impl Iterator<Item = Point<T>> {
+        (0..self.data.len()).map(move |i| self.point_at(i))
+    }
 
-        // Guard against negative
-        if new_row < 0 || new_col < 0 {
-            return None;
+    // the cell at index `i` of the row major data
+    pub fn point_at(&self, i: usize) -> Point<T> {
+        Point {
+            row: i / self.row_size,
+            col: i % self.row_size,
+            value: self.data[i],
         }
+    }
 
-        let new_row = new_row as usize;
-        let new_col = new_col as usize;
-
-        // Get a mutable pointer to the value
-        unsafe {
-            Some(
-                self.data
-                    .as_mut_ptr()
-                    .add(new_row * self.row_size + new_col)
-                    .as_mut()
-                    .unwrap(),
-            )
+    pub fn get_point_in_direction(
+        &self,
+        point: &Point<T>,
+        direction: &Directions,
+    ) -> Option<Point<T>> {
+        // Signed position, so stepping off the top or left is just out of bounds
+        let mut pos = Pos::from(point).step(direction);
+
+        if self.wrapping.horizontal && self.row_size > 0 {
+            pos.x = pos.x.rem_euclid(self.row_size as i64);
+        }
+        if self.wrapping.vertical && self.num_rows() > 0 {
+            pos.y = pos.y.rem_euclid(self.num_rows() as i64);
         }
+
+        self.get_pos(pos).map(|val| Point {
+            row: pos.y as usize,
+            col: pos.x as usize,
+            value: *val,
+        })
     }
 
-    pub fn count_neighbors_of_value<T>(&self, point: &Point, target: u8) -> T
+    pub fn count_neighbors_of_value<N>(&self, point: &Point<T>, target: T) -> N
     where
-        T: TryFrom<usize>,
+        T: PartialEq,
+        N: TryFrom<usize>,
     {
-        let directions = [
-            Directions::Top,
-            Directions::TopRight,
-            Directions::Right,
-            Directions::BottomRight,
-            Directions::Bottom,
-            Directions::BottomLeft,
-            Directions::Left,
-            Directions::TopLeft,
-        ];
-
-        let count = directions
-            .iter()
-            .filter_map(|direction| self.get_point_in_direction(point, direction))
+        let count = self
+            .neighbors8(point)
             .filter(|neighbor| neighbor.value == target)
             .count();
 
-        T::try_from(count).ok().expect("Conversion failed")
+        N::try_from(count).ok().expect("Conversion failed")
+    }
+
+    // neighbours above, right, below and left that are inside the grid
+    pub fn neighbors4(&self, point: &Point<T>) -> impl Iterator<Item = Point<T>> + use<'_, T> {
+        self.neighbors_in_directions(point, &CARDINAL_DIRECTIONS)
+    }
+
+    // all neighbours including diagonals that are inside the grid
+    pub fn neighbors8(&self, point: &Point<T>) -> impl Iterator<Item = Point<T>> + use<'_, T> {
+        self.neighbors_in_directions(point, &ALL_DIRECTIONS)
+    }
+
+    // on a wrapping grid only 1 or 2 cells across, several directions can land on the same cell
+    // or back on `point`, each cell is only yielded once and `point` never is
+    fn neighbors_in_directions(
+        &self,
+        point: &Point<T>,
+        directions: &'static [Directions],
+    ) -> impl Iterator<Item = Point<T>> + use<'_, T> {
+        let point = *point;
+        let wraps = self.wrapping.horizontal || self.wrapping.vertical;
+        let mut seen = Vec::new();
+        directions
+            .iter()
+            .filter_map(move |direction| self.get_point_in_direction(&point, direction))
+            .filter(move |neighbor| {
+                if !wraps {
+                    return true;
+                }
+                let pos = (neighbor.row, neighbor.col);
+                if pos == (point.row, point.col) || seen.contains(&pos) {
+                    return false;
+                }
+                seen.push(pos);
+                true
+            })
     }
 }
 
@@ -213,6 +432,71 @@ impl Display for Grid {
     }
 }
 
+// cells and shape only, two grids that differ just in wrapping are equal
+impl<T: Hash> Hash for Grid<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.row_size.hash(state);
+        self.data.hash(state);
+    }
+}
+
+impl<T: PartialEq> PartialEq for Grid<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.row_size == other.row_size && self.data == other.data
+    }
+}
+
+impl<T: Eq> Eq for Grid<T> {}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        self.get(row, col)
+            .unwrap_or_else(|| panic!("({row}, {col}) is out of bounds"))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        self.get_mut(row, col)
+            .unwrap_or_else(|| panic!("({row}, {col}) is out of bounds"))
+    }
+}
+
+impl<T> Index<Point<T>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point<T>) -> &T {
+        &self[(point.row, point.col)]
+    }
+}
+
+impl<T> IndexMut<Point<T>> for Grid<T> {
+    fn index_mut(&mut self, point: Point<T>) -> &mut T {
+        &mut self[(point.row, point.col)]
+    }
+}
+
+struct GridDisplay<'a, T, F> {
+    grid: &'a Grid<T>,
+    render_cell: F,
+}
+
+impl<T, F> Display for GridDisplay<'_, T, F>
+where
+    F: Fn(&T) -> char,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.grid.iter_rows() {
+            let line: String = row.iter().map(&self.render_cell).collect();
+            writeln!(f, "{line}")?;
+        }
+
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -472,4 +756,243 @@ mod tests {
 
         assert_eq!(found, None);
     }
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    enum Tile {
+        Wall,
+        Open,
+    }
+
+    impl From<u8> for Tile {
+        fn from(value: u8) -> Self {
+            match value {
+                b'#' => Tile::Wall,
+                _ => Tile::Open,
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_with_typed_cells() {
+        let input = "#.#\n..#\n";
+        let grid = Grid::parse_with(input, Tile::from);
+        assert_eq!(grid.get(0, 0), Some(&Tile::Wall));
+        assert_eq!(grid.get(1, 1), Some(&Tile::Open));
+
+        let walls: usize = grid.count_neighbors_of_value(
+            &Point {
+                row: 1,
+                col: 1,
+                value: Tile::Open,
+            },
+            Tile::Wall,
+        );
+        assert_eq!(walls, 3);
+    }
+
+    #[test]
+    fn test_display_with_render_closure() {
+        let input = "#.#\n..#\n";
+        let grid = Grid::parse_with(input, Tile::from);
+        let rendered = grid
+            .display_with(|tile| match tile {
+                Tile::Wall => '█',
+                Tile::Open => ' ',
+            })
+            .to_string();
+        assert_eq!(rendered, "█ █\n  █\n");
+    }
+
+    #[test]
+    fn test_get_mut() {
+        let mut grid = Grid::new("abc\ndef");
+        *grid.get_mut(1, 2).unwrap() = b'X';
+        assert_eq!(grid.get(1, 2), Some(&b'X'));
+        assert!(grid.get_mut(2, 0).is_none());
+        assert!(grid.get_mut(0, 3).is_none());
+    }
+
+    #[test]
+    fn test_set_returns_old_value() {
+        let mut grid = Grid::new("abc\ndef");
+        let point = grid.iter_2d().nth(4).unwrap();
+        assert_eq!(grid.set(&point, b'X'), Some(b'e'));
+        assert_eq!(grid._as_bytes(), b"abcdXf");
+
+        let out_of_bounds = Point {
+            row: 5,
+            col: 0,
+            value: b'a',
+        };
+        assert_eq!(grid.set(&out_of_bounds, b'X'), None);
+    }
+
+    #[test]
+    fn test_swap() {
+        let mut grid = Grid::new("abc\ndef");
+        let a = grid.iter_2d().next().unwrap();
+        let b = grid.iter_2d().last().unwrap();
+        assert!(grid.swap(&a, &b));
+        assert_eq!(grid._as_bytes(), b"fbcdea");
+
+        let out_of_bounds = Point {
+            row: 0,
+            col: 3,
+            value: b'a',
+        };
+        assert!(!grid.swap(&a, &out_of_bounds));
+        assert_eq!(grid._as_bytes(), b"fbcdea");
+    }
+
+    #[test]
+    fn test_index_by_tuple_and_point() {
+        let mut grid = Grid::new("abc\ndef");
+        assert_eq!(grid[(1, 0)], b'd');
+
+        let point = grid.iter_2d().nth(2).unwrap();
+        grid[point] = b'Z';
+        assert_eq!(grid[point], b'Z');
+        assert_eq!(grid[(0, 2)], b'Z');
+    }
+
+    #[test]
+    #[should_panic(expected = "(0, 3) is out of bounds")]
+    fn test_index_out_of_bounds() {
+        let grid = Grid::new("abc\ndef");
+        let _ = grid[(0, 3)];
+    }
+
+    #[test]
+    fn test_try_new_uniform_rows() {
+        let grid = Grid::try_new("abc\ndef\n").unwrap();
+        assert_eq!(grid._as_bytes(), b"abcdef");
+        assert_eq!(grid.row_size, 3);
+    }
+
+    #[test]
+    fn test_try_new_ragged_rows() {
+        let result = Grid::try_new("abc\nde\nfgh");
+        let expected = GridError::RaggedRow {
+            line: 2,
+            expected: 3,
+            found: 2,
+        };
+        assert_eq!(result.err(), Some(expected));
+    }
+
+    #[test]
+    fn test_try_new_empty_line() {
+        let result = Grid::try_new("one\n\ntwo");
+        let error = result.err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 2 has 0 cells, expected 3 like the first line"
+        );
+    }
+
+    #[test]
+    fn test_try_new_with_fill() {
+        let grid = Grid::try_new_with("ab\nabcd\na", Padding::Fill(b' ')).unwrap();
+        assert_eq!(grid.row_size, 4);
+        assert_eq!(grid._as_bytes(), b"ab  abcda   ");
+    }
+
+    #[test]
+    fn test_try_new_with_trim_trailing() {
+        let grid = Grid::try_new_with("ab  \ncd\nef ", Padding::TrimTrailing).unwrap();
+        assert_eq!(grid._as_bytes(), b"abcdef");
+
+        let result = Grid::try_new_with("ab  \nc", Padding::TrimTrailing);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_try_parse_with_fill_maps_fill_byte() {
+        let grid = Grid::try_parse_with("#\n##", Padding::Fill(b'.'), |b| b == b'#').unwrap();
+        assert_eq!(grid.data, vec![true, false, true, true]);
+    }
+
+    #[test]
+    fn test_row_and_col() {
+        let grid = Grid::new("abc\ndef");
+        assert_eq!(grid.num_rows(), 2);
+        assert_eq!(grid.row(1), Some(&b"def"[..]));
+        assert_eq!(grid.row(2), None);
+
+        let col: Vec<u8> = grid.col(1).unwrap().copied().collect();
+        assert_eq!(col, b"be");
+        assert!(grid.col(3).is_none());
+    }
+
+    #[test]
+    fn test_iter_cols() {
+        let grid = Grid::new("abc\ndef");
+        let cols: Vec<Vec<u8>> = grid.iter_cols().map(|col| col.copied().collect()).collect();
+        assert_eq!(cols, vec![b"ad".to_vec(), b"be".to_vec(), b"cf".to_vec()]);
+    }
+
+    #[test]
+    fn test_direction_rotation() {
+        assert_eq!(Directions::Top.turn_right(), Directions::Right);
+        assert_eq!(Directions::Top.turn_left(), Directions::Left);
+        assert_eq!(Directions::TopLeft.turn_right(), Directions::TopRight);
+        assert_eq!(Directions::Left.opposite(), Directions::Right);
+        assert_eq!(Directions::BottomRight.opposite(), Directions::TopLeft);
+        assert_eq!(Directions::Top.rotate(-1), Directions::TopLeft);
+        assert_eq!(Directions::Top.rotate(17), Directions::TopRight);
+    }
+
+    #[test]
+    fn test_direction_delta() {
+        for direction in ALL_DIRECTIONS {
+            let (dr, dc) = direction.delta();
+            let (or, oc) = direction.opposite().delta();
+            assert_eq!((dr + or, dc + oc), (0, 0));
+            assert_eq!(direction.is_cardinal(), dr == 0 || dc == 0);
+        }
+    }
+
+    #[test]
+    fn test_neighbors4_and_neighbors8() {
+        let grid = Grid::new("abc\ndef\nghi");
+        let corner = grid.iter_2d().next().unwrap();
+        let center = grid.iter_2d().nth(4).unwrap();
+
+        let values =
+            |points: Vec<Point>| -> String { points.iter().map(|p| p.value as char).collect() };
+        assert_eq!(values(grid.neighbors4(&center).collect()), "bfhd");
+        assert_eq!(values(grid.neighbors8(&center).collect()), "bcfihgda");
+        assert_eq!(values(grid.neighbors4(&corner).collect()), "bd");
+        assert_eq!(values(grid.neighbors8(&corner).collect()), "bed");
+    }
+
+    #[test]
+    fn test_wrapping_per_axis() {
+        let grid = Grid::new("abc\ndef").with_wrapping(true, false);
+        let corner = grid.iter_2d().next().unwrap();
+        let left = grid.get_point_in_direction(&corner, &Directions::Left);
+        assert_eq!(left.map(|p| (p.row, p.col, p.value)), Some((0, 2, b'c')));
+        assert_eq!(grid.get_point_in_direction(&corner, &Directions::Top), None);
+
+        let torus = grid.with_wrapping(true, true);
+        let up_left = torus.get_point_in_direction(&corner, &Directions::TopLeft);
+        assert_eq!(up_left.map(|p| p.value), Some(b'f'));
+        // every other cell of the 2x3 torus, each counted once
+        assert_eq!(torus.neighbors8(&corner).count(), 5);
+    }
+
+    #[test]
+    fn test_wrapping_neighbors_on_small_grids() {
+        // on a 2x2 torus left and right both land on the same cell
+        let torus = Grid::new("#.\n..").with_wrapping(true, true);
+        let corner = torus.iter_2d().next().unwrap();
+        assert_eq!(torus.neighbors4(&corner).count(), 2);
+        assert_eq!(torus.neighbors8(&corner).count(), 3);
+
+        // a single column wraps back onto itself sideways
+        let column = Grid::new("#\n#\n.").with_wrapping(true, true);
+        let middle = column.iter_2d().nth(1).unwrap();
+        assert_eq!(column.neighbors8(&middle).count(), 2);
+        assert_eq!(column.count_neighbors_of_value::<usize>(&middle, b'#'), 1);
+    }
 }
@@ -0,0 +1,261 @@
+use std::fmt::{self, Display};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub};
+use std::str::FromStr;
+
+// Directions on a flat topped hex grid, ordered clockwise from North which `rotate` relies on
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum HexDirection {
+    North,
+    NorthEast,
+    SouthEast,
+    South,
+    SouthWest,
+    NorthWest,
+}
+
+pub const ALL_HEX_DIRECTIONS: [HexDirection; 6] = [
+    HexDirection::North,
+    HexDirection::NorthEast,
+    HexDirection::SouthEast,
+    HexDirection::South,
+    HexDirection::SouthWest,
+    HexDirection::NorthWest,
+];
+
+#[allow(dead_code)]
+impl HexDirection {
+    // (q, r) axial offset of one step
+    pub fn delta(&self) -> (i64, i64) {
+        match self {
+            HexDirection::North => (0, -1),
+            HexDirection::NorthEast => (1, -1),
+            HexDirection::SouthEast => (1, 0),
+            HexDirection::South => (0, 1),
+            HexDirection::SouthWest => (-1, 1),
+            HexDirection::NorthWest => (-1, 0),
+        }
+    }
+
+    // turn clockwise by `steps` sixths of a turn, negative turns counter clockwise
+    pub fn rotate(&self, steps: i32) -> Self {
+        let index = (*self as i32 + steps).rem_euclid(6);
+        ALL_HEX_DIRECTIONS[index as usize]
+    }
+
+    pub fn opposite(&self) -> Self {
+        self.rotate(3)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ParseHexDirectionError(String);
+
+impl Display for ParseHexDirectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}` is not a hex direction", self.0)
+    }
+}
+
+impl std::error::Error for ParseHexDirectionError {}
+
+// parses `n`, `ne`, `se`, `s`, `sw` and `nw`
+impl FromStr for HexDirection {
+    type Err = ParseHexDirectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "n" => Ok(HexDirection::North),
+            "ne" => Ok(HexDirection::NorthEast),
+            "se" => Ok(HexDirection::SouthEast),
+            "s" => Ok(HexDirection::South),
+            "sw" => Ok(HexDirection::SouthWest),
+            "nw" => Ok(HexDirection::NorthWest),
+            other => Err(ParseHexDirectionError(other.to_string())),
+        }
+    }
+}
+
+// comma separated directions like `ne,sw,nw`
+#[allow(dead_code)]
+pub fn parse_directions(input: &str) -> Result<Vec<HexDirection>, ParseHexDirectionError> {
+    input.trim().split(',').map(str::parse).collect()
+}
+
+// Axial hex coordinate, the third cube coordinate `s` is implied so that q + r + s = 0
+#[derive(Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub struct Hex {
+    pub q: i64,
+    pub r: i64,
+}
+
+#[allow(dead_code)]
+impl Hex {
+    pub const ORIGIN: Hex = Hex { q: 0, r: 0 };
+
+    pub fn new(q: i64, r: i64) -> Self {
+        Self { q, r }
+    }
+
+    // from cube coordinates, which must add up to 0
+    pub fn from_cube(q: i64, r: i64, s: i64) -> Self {
+        debug_assert_eq!(q + r + s, 0, "cube coordinates must add up to 0");
+        Self { q, r }
+    }
+
+    pub fn s(&self) -> i64 {
+        -self.q - self.r
+    }
+
+    pub fn step(&self, direction: &HexDirection) -> Hex {
+        *self + Hex::from(*direction)
+    }
+
+    // fewest steps between the two hexes
+    pub fn distance(&self, other: &Hex) -> i64 {
+        let diff = *self - *other;
+        (diff.q.abs() + diff.r.abs() + diff.s().abs()) / 2
+    }
+
+    pub fn neighbors(&self) -> impl Iterator<Item = Hex> + use<> {
+        let hex = *self;
+        ALL_HEX_DIRECTIONS
+            .iter()
+            .map(move |direction| hex.step(direction))
+    }
+
+    // every hex exactly `radius` steps away, clockwise from the one straight north
+    pub fn ring(&self, radius: u32) -> Vec<Hex> {
+        if radius == 0 {
+            return vec![*self];
+        }
+
+        // walk the six sides, each side is `radius` steps long
+        let mut hex = *self + Hex::from(HexDirection::North) * radius as i64;
+        let mut ring = Vec::with_capacity(6 * radius as usize);
+        for direction in ALL_HEX_DIRECTIONS {
+            let side = direction.rotate(2);
+            for _ in 0..radius {
+                ring.push(hex);
+                hex = hex.step(&side);
+            }
+        }
+        ring
+    }
+
+    // every hex at most `radius` steps away, ring by ring from the centre
+    pub fn spiral(&self, radius: u32) -> Vec<Hex> {
+        (0..=radius).flat_map(|r| self.ring(r)).collect()
+    }
+}
+
+impl From<HexDirection> for Hex {
+    fn from(direction: HexDirection) -> Self {
+        let (q, r) = direction.delta();
+        Hex::new(q, r)
+    }
+}
+
+impl Add for Hex {
+    type Output = Hex;
+
+    fn add(self, rhs: Hex) -> Hex {
+        Hex::new(self.q + rhs.q, self.r + rhs.r)
+    }
+}
+
+impl Sub for Hex {
+    type Output = Hex;
+
+    fn sub(self, rhs: Hex) -> Hex {
+        Hex::new(self.q - rhs.q, self.r - rhs.r)
+    }
+}
+
+impl Neg for Hex {
+    type Output = Hex;
+
+    fn neg(self) -> Hex {
+        Hex::new(-self.q, -self.r)
+    }
+}
+
+impl Mul<i64> for Hex {
+    type Output = Hex;
+
+    fn mul(self, rhs: i64) -> Hex {
+        Hex::new(self.q * rhs, self.r * rhs)
+    }
+}
+
+impl AddAssign for Hex {
+    fn add_assign(&mut self, rhs: Hex) {
+        *self = *self + rhs;
+    }
+}
+
+impl Display for Hex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{},{}", self.q, self.r, self.s())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn walk(path: &str) -> Hex {
+        parse_directions(path)
+            .unwrap()
+            .iter()
+            .fold(Hex::ORIGIN, |hex, direction| hex.step(direction))
+    }
+
+    #[test]
+    fn test_walk_distances() {
+        assert_eq!(walk("ne,ne,ne").distance(&Hex::ORIGIN), 3);
+        assert_eq!(walk("ne,ne,sw,sw").distance(&Hex::ORIGIN), 0);
+        assert_eq!(walk("ne,ne,s,s").distance(&Hex::ORIGIN), 2);
+        assert_eq!(walk("se,sw,se,sw,sw").distance(&Hex::ORIGIN), 3);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            parse_directions("n,e"),
+            Err(ParseHexDirectionError("e".to_string()))
+        );
+        assert_eq!(" sw ".parse(), Ok(HexDirection::SouthWest));
+    }
+
+    #[test]
+    fn test_rotate_and_opposite() {
+        assert_eq!(HexDirection::North.rotate(1), HexDirection::NorthEast);
+        assert_eq!(HexDirection::North.rotate(-1), HexDirection::NorthWest);
+        for direction in ALL_HEX_DIRECTIONS {
+            assert_eq!(
+                Hex::from(direction) + Hex::from(direction.opposite()),
+                Hex::ORIGIN
+            );
+        }
+    }
+
+    #[test]
+    fn test_neighbors_and_rings() {
+        let centre = Hex::new(2, -1);
+        assert!(centre.neighbors().all(|hex| hex.distance(&centre) == 1));
+
+        for radius in 0..4 {
+            let ring = centre.ring(radius);
+            assert_eq!(
+                ring.len(),
+                if radius == 0 { 1 } else { 6 * radius as usize }
+            );
+            assert!(
+                ring.iter()
+                    .all(|hex| hex.distance(&centre) == radius as i64)
+            );
+        }
+        assert_eq!(centre.spiral(2).len(), 19);
+        assert_eq!(centre.ring(1)[0], centre.step(&HexDirection::North));
+    }
+}
@@ -0,0 +1,624 @@
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use super::grid::Grid;
+
+pub type Rgb = [u8; 3];
+
+// Colour for each byte value, anything not listed gets the fallback
+#[derive(Clone, Debug)]
+pub struct Palette {
+    colors: HashMap<u8, Rgb>,
+    fallback: Rgb,
+}
+
+#[allow(dead_code)]
+impl Palette {
+    pub fn new(fallback: Rgb) -> Self {
+        Self {
+            colors: HashMap::new(),
+            fallback,
+        }
+    }
+
+    pub fn with(mut self, byte: u8, color: Rgb) -> Self {
+        self.colors.insert(byte, color);
+        self
+    }
+
+    pub fn color(&self, byte: u8) -> Rgb {
+        *self.colors.get(&byte).unwrap_or(&self.fallback)
+    }
+}
+
+impl Default for Palette {
+    // dark background with light walls, `#` and `@` are the usual solid cells
+    fn default() -> Self {
+        Self::new([24, 24, 32])
+            .with(b'#', [220, 220, 220])
+            .with(b'@', [220, 180, 60])
+    }
+}
+
+// distinct colours for neighbouring labels, by stepping the hue with the golden ratio
+pub fn label_color(label: usize) -> Rgb {
+    let hue = (label as f64 * 0.618_033_988_75).fract() * 6.0;
+    let x = 1.0 - (hue % 2.0 - 1.0).abs();
+    let (r, g, b) = match hue as u32 {
+        0 => (1.0, x, 0.0),
+        1 => (x, 1.0, 0.0),
+        2 => (0.0, 1.0, x),
+        3 => (0.0, x, 1.0),
+        4 => (x, 0.0, 1.0),
+        _ => (1.0, 0.0, x),
+    };
+    // not fully saturated so it's easy on the eyes
+    let channel = |c: f64| (64.0 + c * 176.0) as u8;
+    [channel(r), channel(g), channel(b)]
+}
+
+#[derive(Debug)]
+pub enum ImageError {
+    // a gif can only hold a 256 colour palette
+    TooManyColors(usize),
+    // the size doesn't fit the format's header fields
+    TooLarge {
+        format: &'static str,
+        width: usize,
+        height: usize,
+    },
+    Io(io::Error),
+}
+
+impl Display for ImageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImageError::TooManyColors(count) => {
+                write!(f, "{count} colours used, a gif can only hold 256")
+            }
+            ImageError::TooLarge {
+                format,
+                width,
+                height,
+            } => write!(f, "{width}x{height} is too large for a {format}"),
+            ImageError::Io(err) => write!(f, "could not write image: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ImageError {}
+
+impl From<io::Error> for ImageError {
+    fn from(err: io::Error) -> Self {
+        ImageError::Io(err)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    // row by row from the top left
+    pub pixels: Vec<Rgb>,
+}
+
+#[allow(dead_code)]
+impl Image {
+    // one pixel per cell, coloured by the palette
+    pub fn from_grid(grid: &Grid, palette: &Palette) -> Self {
+        Self::from_grid_with(grid, |&byte| palette.color(byte))
+    }
+
+    // one pixel per cell, every region label in its own colour
+    pub fn from_labels(labels: &Grid<usize>) -> Self {
+        Self::from_grid_with(labels, |&label| label_color(label))
+    }
+
+    pub fn from_grid_with<T, F>(grid: &Grid<T>, color: F) -> Self
+    where
+        F: Fn(&T) -> Rgb,
+    {
+        Self {
+            width: grid.row_size,
+            height: grid.num_rows(),
+            pixels: grid.data.iter().map(color).collect(),
+        }
+    }
+
+    // every pixel blown up to a `factor` by `factor` square, puzzle grids are tiny
+    pub fn scaled(&self, factor: usize) -> Self {
+        let width = self.width * factor;
+        let pixels = (0..self.height * factor)
+            .flat_map(|y| (0..width).map(move |x| (x / factor, y / factor)))
+            .map(|(x, y)| self.pixels[y * self.width + x])
+            .collect();
+
+        Self {
+            width,
+            height: self.height * factor,
+            pixels,
+        }
+    }
+
+    // binary ppm, just a header and the raw pixels
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut bytes = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        bytes.extend(self.pixels.iter().flatten());
+        bytes
+    }
+
+    // truecolour png, compressed with stored deflate blocks so it stays uncompressed
+    pub fn to_png(&self) -> Result<Vec<u8>, ImageError> {
+        let too_large = || ImageError::TooLarge {
+            format: "png",
+            width: self.width,
+            height: self.height,
+        };
+        // sizes and chunk lengths are at most 2^31 - 1
+        let to_u32 = |n: usize| u32::try_from(n).ok().filter(|&n| n <= i32::MAX as u32);
+        let width = to_u32(self.width).ok_or_else(too_large)?;
+        let height = to_u32(self.height).ok_or_else(too_large)?;
+
+        let mut header = Vec::new();
+        header.extend(width.to_be_bytes());
+        header.extend(height.to_be_bytes());
+        // 8 bit depth, rgb, default compression, filtering and no interlace
+        header.extend([8, 2, 0, 0, 0]);
+
+        // every row starts with filter type 0 (none)
+        let mut raw = Vec::with_capacity(self.height * (self.width * 3 + 1));
+        for row in self.pixels.chunks(self.width.max(1)) {
+            raw.push(0);
+            raw.extend(row.iter().flatten());
+        }
+
+        let data = zlib_stored(&raw);
+        to_u32(data.len()).ok_or_else(too_large)?;
+
+        let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+        write_png_chunk(&mut png, b"IHDR", &header);
+        write_png_chunk(&mut png, b"IDAT", &data);
+        write_png_chunk(&mut png, b"IEND", &[]);
+        Ok(png)
+    }
+
+    pub fn save_ppm(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        fs::write(path, self.to_ppm())
+    }
+
+    pub fn save_png(&self, path: impl AsRef<Path>) -> Result<(), ImageError> {
+        fs::write(path, self.to_png()?)?;
+        Ok(())
+    }
+}
+
+// Collects the frames of a simulation and writes them out as a looping gif
+#[derive(Clone, Debug)]
+pub struct FrameRecorder {
+    frames: Vec<Image>,
+    // time each frame is shown, in hundredths of a second
+    delay: u16,
+}
+
+#[allow(dead_code)]
+impl FrameRecorder {
+    pub fn new(delay: u16) -> Self {
+        Self {
+            frames: Vec::new(),
+            delay,
+        }
+    }
+
+    // every frame has to be the same size as the first
+    pub fn record(&mut self, frame: Image) {
+        if let Some(first) = self.frames.first() {
+            assert_eq!(
+                (first.width, first.height),
+                (frame.width, frame.height),
+                "frames have different sizes"
+            );
+        }
+        self.frames.push(frame);
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    pub fn to_gif(&self) -> Result<Vec<u8>, ImageError> {
+        let (width, height) = self
+            .frames
+            .first()
+            .map_or((0, 0), |frame| (frame.width, frame.height));
+        let (Ok(width), Ok(height)) = (u16::try_from(width), u16::try_from(height)) else {
+            return Err(ImageError::TooLarge {
+                format: "gif",
+                width,
+                height,
+            });
+        };
+
+        // one global colour table shared by every frame
+        let mut colors: Vec<Rgb> = Vec::new();
+        let mut index_of: HashMap<Rgb, usize> = HashMap::new();
+        for &pixel in self.frames.iter().flat_map(|frame| &frame.pixels) {
+            index_of.entry(pixel).or_insert_with(|| {
+                colors.push(pixel);
+                colors.len() - 1
+            });
+        }
+        if colors.len() > 256 {
+            return Err(ImageError::TooManyColors(colors.len()));
+        }
+
+        // the table holds 2^(bits) entries, at least 2
+        let bits = colors.len().max(2).next_power_of_two().trailing_zeros() as u8;
+
+        let mut gif = b"GIF89a".to_vec();
+        gif.extend(width.to_le_bytes());
+        gif.extend(height.to_le_bytes());
+        gif.extend([0x80 | 0x70 | (bits - 1), 0, 0]);
+        for i in 0..1 << bits {
+            gif.extend(colors.get(i).unwrap_or(&[0, 0, 0]));
+        }
+
+        // NETSCAPE2.0 application extension, loop forever
+        gif.extend([0x21, 0xff, 0x0b]);
+        gif.extend(b"NETSCAPE2.0");
+        gif.extend([0x03, 0x01, 0x00, 0x00, 0x00]);
+
+        let min_code_size = bits.max(2);
+        for frame in &self.frames {
+            // graphic control extension with the frame delay
+            gif.extend([0x21, 0xf9, 0x04, 0x00]);
+            gif.extend(self.delay.to_le_bytes());
+            gif.extend([0x00, 0x00]);
+
+            // image descriptor covering the whole screen, no local colour table
+            gif.push(0x2c);
+            gif.extend([0, 0, 0, 0]);
+            gif.extend(width.to_le_bytes());
+            gif.extend(height.to_le_bytes());
+            gif.push(0);
+
+            let indices: Vec<u8> = frame
+                .pixels
+                .iter()
+                .map(|pixel| index_of[pixel] as u8)
+                .collect();
+            gif.push(min_code_size);
+            for block in lzw_encode(&indices, min_code_size).chunks(255) {
+                gif.push(block.len() as u8);
+                gif.extend(block);
+            }
+            gif.push(0);
+        }
+
+        gif.push(0x3b);
+        Ok(gif)
+    }
+
+    pub fn save_gif(&self, path: impl AsRef<Path>) -> Result<(), ImageError> {
+        fs::write(path, self.to_gif()?)?;
+        Ok(())
+    }
+}
+
+// `to_png` has already checked that the length fits
+fn write_png_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend((data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend(kind);
+    png.extend(data);
+    let crc = crc32(&png[start..]);
+    png.extend(crc.to_be_bytes());
+}
+
+// zlib stream made of uncompressed deflate blocks
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    // deflate with a 32k window and no preset dictionary, the check bits make it a multiple of 31
+    let mut out = vec![0x78, 0x01];
+    let mut blocks = data.chunks(u16::MAX as usize).peekable();
+    if blocks.peek().is_none() {
+        out.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let is_last = blocks.peek().is_none();
+        let len = block.len() as u16;
+        out.push(is_last as u8);
+        out.extend(len.to_le_bytes());
+        out.extend((!len).to_le_bytes());
+        out.extend(block);
+    }
+    out.extend(adler32(data).to_be_bytes());
+    out
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in bytes {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            // reversed polynomial 0x04c11db7
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xedb88320 & mask);
+        }
+    }
+    !crc
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    const MOD: u32 = 65521;
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in bytes {
+        a = (a + byte as u32) % MOD;
+        b = (b + a) % MOD;
+    }
+    b << 16 | a
+}
+
+// variable width lzw as used by gif, codes are packed least significant bit first
+fn lzw_encode(indices: &[u8], min_code_size: u8) -> Vec<u8> {
+    let clear = 1u16 << min_code_size;
+    let end = clear + 1;
+
+    let mut writer = BitWriter::default();
+    let mut dictionary: HashMap<(u16, u8), u16> = HashMap::new();
+    let mut next_code = end + 1;
+    let mut code_size = min_code_size + 1;
+    writer.write(clear, code_size);
+
+    let Some((&first, rest)) = indices.split_first() else {
+        writer.write(end, code_size);
+        return writer.finish();
+    };
+
+    let mut prefix = first as u16;
+    for &index in rest {
+        if let Some(&code) = dictionary.get(&(prefix, index)) {
+            prefix = code;
+            continue;
+        }
+
+        writer.write(prefix, code_size);
+        if next_code < 4096 {
+            dictionary.insert((prefix, index), next_code);
+            next_code += 1;
+            // the decoder is one entry behind, so widen once it would need the next size
+            if next_code > 1 << code_size {
+                code_size += 1;
+            }
+        } else {
+            writer.write(clear, code_size);
+            dictionary.clear();
+            next_code = end + 1;
+            code_size = min_code_size + 1;
+        }
+        prefix = index as u16;
+    }
+
+    writer.write(prefix, code_size);
+    writer.write(end, code_size);
+    writer.finish()
+}
+
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    buffer: u32,
+    filled: u8,
+}
+
+impl BitWriter {
+    fn write(&mut self, code: u16, size: u8) {
+        self.buffer |= (code as u32) << self.filled;
+        self.filled += size;
+        while self.filled >= 8 {
+            self.bytes.push(self.buffer as u8);
+            self.buffer >>= 8;
+            self.filled -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.filled > 0 {
+            self.bytes.push(self.buffer as u8);
+        }
+        self.bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // reference gif lzw decoder, to check the encoder round trips
+    fn lzw_decode(data: &[u8], min_code_size: u8) -> Vec<u8> {
+        let clear = 1usize << min_code_size;
+        let end = clear + 1;
+        let reset = || -> Vec<Vec<u8>> {
+            let mut table: Vec<Vec<u8>> = (0..clear).map(|i| vec![i as u8]).collect();
+            table.extend([Vec::new(), Vec::new()]);
+            table
+        };
+
+        let mut table = reset();
+        let mut code_size = min_code_size + 1;
+        let mut previous: Option<Vec<u8>> = None;
+        let mut out = Vec::new();
+        let (mut buffer, mut filled, mut bytes) = (0u32, 0u8, data.iter());
+
+        loop {
+            while filled < code_size {
+                buffer |= (*bytes.next().unwrap() as u32) << filled;
+                filled += 8;
+            }
+            let code = (buffer & ((1 << code_size) - 1)) as usize;
+            buffer >>= code_size;
+            filled -= code_size;
+
+            if code == clear {
+                table = reset();
+                code_size = min_code_size + 1;
+                previous = None;
+                continue;
+            }
+            if code == end {
+                return out;
+            }
+
+            let entry = match (table.get(code), &previous) {
+                (Some(entry), _) => entry.clone(),
+                (None, Some(prev)) => [prev.clone(), vec![prev[0]]].concat(),
+                (None, None) => panic!("bad first code"),
+            };
+            out.extend(&entry);
+
+            if let Some(prev) = previous
+                && table.len() < 4096
+            {
+                table.push([prev, vec![entry[0]]].concat());
+                if table.len() == 1 << code_size && code_size < 12 {
+                    code_size += 1;
+                }
+            }
+            previous = Some(entry);
+        }
+    }
+
+    #[test]
+    fn test_checksums() {
+        assert_eq!(crc32(b"IEND"), 0xae426082);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e60398);
+    }
+
+    #[test]
+    fn test_lzw_round_trip() {
+        let short = b"\x00\x01\x00\x01\x00\x01\x01\x01\x01\x00".to_vec();
+        assert_eq!(lzw_decode(&lzw_encode(&short, 2), 2), short);
+
+        // long enough to fill the 4096 entry table and start over
+        let mut state = 7u32;
+        let noisy: Vec<u8> = (0..50_000)
+            .map(|_| {
+                state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+                (state >> 16) as u8 % 16
+            })
+            .collect();
+        assert_eq!(lzw_decode(&lzw_encode(&noisy, 4), 4), noisy);
+    }
+
+    #[test]
+    fn test_ppm() {
+        let grid = Grid::new("#.");
+        let image = Image::from_grid(&grid, &Palette::new([0, 0, 0]).with(b'#', [255, 0, 0]));
+        assert_eq!(image.to_ppm(), b"P6\n2 1\n255\n\xff\x00\x00\x00\x00\x00");
+    }
+
+    #[test]
+    fn test_png_layout() {
+        let image = Image::from_grid(&Grid::new("#.\n.#"), &Palette::default()).scaled(3);
+        assert_eq!((image.width, image.height), (6, 6));
+
+        let png = image.to_png().unwrap();
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(&png[12..16], b"IHDR");
+        assert_eq!(&png[16..24], &[0, 0, 0, 6, 0, 0, 0, 6]);
+        assert_eq!(
+            &png[png.len() - 12..],
+            b"\x00\x00\x00\x00IEND\xae\x42\x60\x82"
+        );
+
+        // the image data is stored as is, filter byte then rgb
+        let idat = &png[33..];
+        assert_eq!(&idat[4..8], b"IDAT");
+        assert_eq!(idat[8..10], [0x78, 0x01]);
+        assert_eq!(idat[15], 0);
+        assert_eq!(idat[16..19], [220, 220, 220]);
+    }
+
+    #[test]
+    fn test_gif_frames() {
+        let palette = Palette::default();
+        let mut recorder = FrameRecorder::new(10);
+        let mut grid = Grid::new("@@.\n.@@");
+        recorder.record(Image::from_grid(&grid, &palette));
+        let first = grid.iter_2d().next().unwrap();
+        grid.set(&first, b'.');
+        recorder.record(Image::from_grid(&grid, &palette));
+
+        let gif = recorder.to_gif().unwrap();
+        assert_eq!(&gif[..6], b"GIF89a");
+        assert_eq!(gif[6..10], [3, 0, 2, 0]);
+        assert_eq!(*gif.last().unwrap(), 0x3b);
+
+        // two colours used, so a 2 entry table, then the 19 byte loop extension
+        assert_eq!(gif[10] & 0x07, 0);
+        assert_eq!(&gif[22..33], b"NETSCAPE2.0");
+
+        let mut rest = &gif[38..];
+        let mut frames = Vec::new();
+        while rest[0] != 0x3b {
+            assert_eq!(rest[..4], [0x21, 0xf9, 0x04, 0x00]);
+            assert_eq!(rest[4..6], [10, 0]);
+            assert_eq!(rest[8], 0x2c);
+            let min_code_size = rest[18];
+            rest = &rest[19..];
+
+            let mut data = Vec::new();
+            while rest[0] != 0 {
+                let len = rest[0] as usize;
+                data.extend(&rest[1..=len]);
+                rest = &rest[len + 1..];
+            }
+            rest = &rest[1..];
+            frames.push(lzw_decode(&data, min_code_size));
+        }
+
+        // `@` came first so it's colour 0
+        assert_eq!(frames, vec![vec![0, 0, 1, 1, 0, 0], vec![1, 0, 1, 1, 0, 0]]);
+    }
+
+    #[test]
+    fn test_gif_palette_limit() {
+        let labels = Grid::from_vec((0..300).collect(), 20);
+        let mut recorder = FrameRecorder::new(10);
+        recorder.record(Image::from_labels(&labels));
+        assert!(matches!(
+            recorder.to_gif(),
+            Err(ImageError::TooManyColors(_))
+        ));
+    }
+
+    #[test]
+    fn test_oversized_images() {
+        let wide = Image {
+            width: u16::MAX as usize + 1,
+            height: 0,
+            pixels: Vec::new(),
+        };
+        let mut recorder = FrameRecorder::new(10);
+        recorder.record(wide);
+        assert!(matches!(
+            recorder.to_gif(),
+            Err(ImageError::TooLarge { format: "gif", .. })
+        ));
+
+        let tall = Image {
+            width: 0,
+            height: 1 << 31,
+            pixels: Vec::new(),
+        };
+        assert!(matches!(
+            tall.to_png(),
+            Err(ImageError::TooLarge { format: "png", .. })
+        ));
+    }
+}
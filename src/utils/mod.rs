@@ -1,3 +1,20 @@
+pub mod automaton;
+pub mod bigint;
+pub mod bit_grid;
+pub mod cycle;
+pub mod diff;
 pub mod grid;
+pub mod hex;
+pub mod image;
+pub mod ocr;
+pub mod pathfinding;
+pub mod pos;
 pub mod range;
+pub mod ray;
+pub mod region;
+pub mod render;
+pub mod search;
 pub mod solution;
+pub mod sparse_grid;
+pub mod transform;
+pub mod vec3;
@@ -0,0 +1,184 @@
+// Block letter fonts used by puzzles that "draw" their answer with `#` and `.`
+// Glyphs are stored with blank columns trimmed, since letters are split on blank columns
+const FONT_4X6: [(char, &str); 18] = [
+    ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+    ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+    ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+    ('E', "####\n#...\n###.\n#...\n#...\n####"),
+    ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+    ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+    ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+    ('I', "###\n.#.\n.#.\n.#.\n.#.\n###"),
+    ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+    ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+    ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+    ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+    ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+    ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+    ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('Y', "#...#\n#...#\n.#.#.\n..#..\n..#..\n..#.."),
+    ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+];
+
+const FONT_6X10: [(char, &str); 15] = [
+    (
+        'A',
+        "..##..\n.#..#.\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'B',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#....#\n#....#\n#....#\n#....#\n#####.",
+    ),
+    (
+        'C',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#....#\n.####.",
+    ),
+    (
+        'E',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'F',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'G',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#..###\n#....#\n#....#\n#...##\n.###.#",
+    ),
+    (
+        'H',
+        "#....#\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'J',
+        "...###\n....#.\n....#.\n....#.\n....#.\n....#.\n....#.\n#...#.\n#...#.\n.###..",
+    ),
+    (
+        'K',
+        "#....#\n#...#.\n#..#..\n#.#...\n##....\n##....\n#.#...\n#..#..\n#...#.\n#....#",
+    ),
+    (
+        'L',
+        "#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'N',
+        "#....#\n##...#\n##...#\n#.#..#\n#.#..#\n#..#.#\n#..#.#\n#...##\n#...##\n#....#",
+    ),
+    (
+        'P',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'R',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#..#..\n#...#.\n#...#.\n#....#\n#....#",
+    ),
+    (
+        'X',
+        "#....#\n#....#\n.#..#.\n.#..#.\n..##..\n..##..\n.#..#.\n.#..#.\n#....#\n#....#",
+    ),
+    (
+        'Z',
+        "######\n.....#\n.....#\n....#.\n...#..\n..#...\n.#....\n#.....\n#.....\n######",
+    ),
+];
+
+// decodes a picture of block letters drawn with `#` (anything else is treated as blank)
+// supports the 4x6 and 6x10 fonts, picked by the picture height
+pub fn recognize(picture: &str) -> Option<String> {
+    let rows: Vec<Vec<bool>> = picture
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.chars().map(|c| c == '#').collect())
+        .collect();
+
+    let font: &[(char, &str)] = match rows.len() {
+        6 => &FONT_4X6,
+        10 => &FONT_6X10,
+        _ => return None,
+    };
+
+    let width = rows.iter().map(|row| row.len()).max()?;
+    let is_lit = |row: usize, col: usize| rows[row].get(col).copied().unwrap_or(false);
+    let is_blank_col = |col: usize| (0..rows.len()).all(|row| !is_lit(row, col));
+
+    let mut text = String::new();
+    let mut col = 0;
+    while col < width {
+        if is_blank_col(col) {
+            col += 1;
+            continue;
+        }
+
+        // Letters are separated by at least one blank column
+        let start = col;
+        while col < width && !is_blank_col(col) {
+            col += 1;
+        }
+
+        let glyph: Vec<String> = (0..rows.len())
+            .map(|row| {
+                (start..col)
+                    .map(|c| if is_lit(row, c) { '#' } else { '.' })
+                    .collect()
+            })
+            .collect();
+        let glyph = glyph.join("\n");
+
+        let (letter, _) = font.iter().find(|(_, pattern)| *pattern == glyph)?;
+        text.push(*letter);
+    }
+
+    if text.is_empty() { None } else { Some(text) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recognize_small_font() {
+        let picture = "\
+#..#.####.###..
+#..#.#....#..#.
+####.###..###..
+#..#.#....#..#.
+#..#.#....#..#.
+#..#.####.###..";
+        assert_eq!(recognize(picture), Some("HEB".to_string()));
+    }
+
+    #[test]
+    fn test_recognize_large_font() {
+        let picture = "\
+#....#..######
+#....#..#.....
+.#..#...#.....
+.#..#...#.....
+..##....#####.
+..##....#.....
+.#..#...#.....
+.#..#...#.....
+#....#..#.....
+#....#..######";
+        assert_eq!(recognize(picture), Some("XE".to_string()));
+    }
+
+    #[test]
+    fn test_recognize_accepts_other_blank_chars() {
+        let picture = " ## \n#  #\n#  #\n####\n#  #\n#  #\n";
+        assert_eq!(recognize(picture), Some("A".to_string()));
+    }
+
+    #[test]
+    fn test_recognize_unknown_glyph() {
+        let picture = "####\n####\n####\n####\n####\n####";
+        assert_eq!(recognize(picture), None);
+    }
+
+    #[test]
+    fn test_recognize_unsupported_height() {
+        assert_eq!(recognize("#\n#\n#"), None);
+    }
+}
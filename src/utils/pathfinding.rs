@@ -0,0 +1,406 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+
+use super::grid::{ALL_DIRECTIONS, CARDINAL_DIRECTIONS, Directions, Grid, Point, Wrapping};
+
+// Which neighbours a search may step to
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Neighborhood {
+    Four,
+    Eight,
+}
+
+impl Neighborhood {
+    pub fn directions(&self) -> &'static [Directions] {
+        match self {
+            Neighborhood::Four => &CARDINAL_DIRECTIONS,
+            Neighborhood::Eight => &ALL_DIRECTIONS,
+        }
+    }
+
+    // neighbours of `point` that are inside the grid
+    fn neighbors<'a, T: Copy>(
+        &self,
+        grid: &'a Grid<T>,
+        point: &Point<T>,
+    ) -> Box<dyn Iterator<Item = Point<T>> + 'a> {
+        match self {
+            Neighborhood::Four => Box::new(grid.neighbors4(point)),
+            Neighborhood::Eight => Box::new(grid.neighbors8(point)),
+        }
+    }
+
+    // lower bound on the number of steps between two cells
+    // on a wrapping axis going around the other way can be shorter
+    fn steps_between(
+        &self,
+        from: (usize, usize),
+        to: (usize, usize),
+        (num_rows, row_size): (usize, usize),
+        wrapping: Wrapping,
+    ) -> u64 {
+        let along = |a: usize, b: usize, size: usize, wraps: bool| {
+            let d = a.abs_diff(b);
+            if wraps {
+                d.min(size - d) as u64
+            } else {
+                d as u64
+            }
+        };
+        let dr = along(from.0, to.0, num_rows, wrapping.vertical);
+        let dc = along(from.1, to.1, row_size, wrapping.horizontal);
+        match self {
+            Neighborhood::Four => dr + dc,
+            Neighborhood::Eight => dr.max(dc),
+        }
+    }
+}
+
+// Distances from the start of a search, and every predecessor on a shortest path
+// Positions are (row, col)
+#[derive(Debug)]
+pub struct SearchResult {
+    row_size: usize,
+    distances: Vec<Option<u64>>,
+    predecessors: Vec<Vec<usize>>,
+}
+
+#[allow(dead_code)]
+impl SearchResult {
+    fn new<T>(grid: &Grid<T>) -> Self {
+        Self {
+            row_size: grid.row_size,
+            distances: vec![None; grid.data.len()],
+            predecessors: vec![Vec::new(); grid.data.len()],
+        }
+    }
+
+    fn index_of(&self, row: usize, col: usize) -> Option<usize> {
+        if col >= self.row_size {
+            return None;
+        }
+        let i = row * self.row_size + col;
+        (i < self.distances.len()).then_some(i)
+    }
+
+    fn position_of(&self, i: usize) -> (usize, usize) {
+        (i / self.row_size, i % self.row_size)
+    }
+
+    pub fn distance(&self, row: usize, col: usize) -> Option<u64> {
+        self.distances[self.index_of(row, col)?]
+    }
+
+    // cells one step before this one on some shortest path
+    pub fn predecessors(&self, row: usize, col: usize) -> Vec<(usize, usize)> {
+        match self.index_of(row, col) {
+            Some(i) => self.predecessors[i]
+                .iter()
+                .map(|&p| self.position_of(p))
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    // one shortest path, from the start to this cell inclusive
+    pub fn path_to(&self, row: usize, col: usize) -> Option<Vec<(usize, usize)>> {
+        let mut current = self.index_of(row, col)?;
+        self.distances[current]?;
+
+        let mut path = vec![self.position_of(current)];
+        while let Some(&previous) = self.predecessors[current].first() {
+            path.push(self.position_of(previous));
+            current = previous;
+        }
+
+        path.reverse();
+        Some(path)
+    }
+
+    // every shortest path, from the start to this cell inclusive
+    pub fn all_paths_to(&self, row: usize, col: usize) -> Vec<Vec<(usize, usize)>> {
+        let Some(target) = self.index_of(row, col) else {
+            return Vec::new();
+        };
+        if self.distances[target].is_none() {
+            return Vec::new();
+        }
+
+        // walk back from the target, branching on every predecessor
+        let mut paths = Vec::new();
+        let mut stack = vec![vec![target]];
+        while let Some(partial) = stack.pop() {
+            let last = *partial.last().unwrap();
+            if self.predecessors[last].is_empty() {
+                paths.push(partial.iter().rev().map(|&i| self.position_of(i)).collect());
+                continue;
+            }
+
+            for &previous in &self.predecessors[last] {
+                let mut next = partial.clone();
+                next.push(previous);
+                stack.push(next);
+            }
+        }
+
+        paths
+    }
+
+    // record a step to `to` costing `distance`, true if it improved the best known distance
+    // a tie only adds `from` as a predecessor when it is strictly closer to the start,
+    // so zero cost steps can't make two cells each other's predecessor
+    fn relax(&mut self, from: usize, to: usize, distance: u64) -> bool {
+        match self.distances[to] {
+            Some(best) if distance > best => false,
+            Some(best) if distance == best => {
+                if self.distances[from].is_some_and(|d| d < distance) {
+                    self.predecessors[to].push(from);
+                }
+                false
+            }
+            _ => {
+                self.distances[to] = Some(distance);
+                self.predecessors[to] = vec![from];
+                true
+            }
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl<T: Copy> Grid<T> {
+    // breadth first search, every step costs 1
+    // `passable(from, to)` decides if a step can be taken
+    pub fn bfs<F>(&self, start: &Point<T>, neighborhood: Neighborhood, passable: F) -> SearchResult
+    where
+        F: Fn(&Point<T>, &Point<T>) -> bool,
+    {
+        let mut result = SearchResult::new(self);
+        let Some(start_index) = result.index_of(start.row, start.col) else {
+            return result;
+        };
+
+        result.distances[start_index] = Some(0);
+        let mut queue = VecDeque::from([start_index]);
+
+        while let Some(current) = queue.pop_front() {
+            let point = self.point_at(current);
+            let distance = result.distances[current].unwrap() + 1;
+
+            for neighbor in neighborhood.neighbors(self, &point) {
+                if !passable(&point, &neighbor) {
+                    continue;
+                }
+
+                let next = neighbor.row * self.row_size + neighbor.col;
+                if result.relax(current, next, distance) {
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        result
+    }
+
+    // shortest paths with weighted steps
+    // `cost(from, to)` gives the cost of a step, or None if it can't be taken
+    // steps may cost 0, those only count as predecessors on the route that first reached a cell
+    pub fn dijkstra<F>(&self, start: &Point<T>, neighborhood: Neighborhood, cost: F) -> SearchResult
+    where
+        F: Fn(&Point<T>, &Point<T>) -> Option<u64>,
+    {
+        self.best_first(start, None, neighborhood, cost)
+    }
+
+    // shortest path to a single goal, guided by a manhattan (or chebyshev for 8 neighbours) heuristic
+    // every step must cost at least 1 for the heuristic to hold
+    // only distances and predecessors on the way to the goal are complete
+    pub fn astar<F>(
+        &self,
+        start: &Point<T>,
+        goal: &Point<T>,
+        neighborhood: Neighborhood,
+        cost: F,
+    ) -> SearchResult
+    where
+        F: Fn(&Point<T>, &Point<T>) -> Option<u64>,
+    {
+        self.best_first(start, Some((goal.row, goal.col)), neighborhood, cost)
+    }
+
+    // dijkstra when there is no goal, A* when there is one
+    fn best_first<F>(
+        &self,
+        start: &Point<T>,
+        goal: Option<(usize, usize)>,
+        neighborhood: Neighborhood,
+        cost: F,
+    ) -> SearchResult
+    where
+        F: Fn(&Point<T>, &Point<T>) -> Option<u64>,
+    {
+        let mut result = SearchResult::new(self);
+        let Some(start_index) = result.index_of(start.row, start.col) else {
+            return result;
+        };
+
+        let row_size = self.row_size;
+        let shape = (self.num_rows(), row_size);
+        let heuristic = |i: usize| match goal {
+            Some(goal) => {
+                neighborhood.steps_between((i / row_size, i % row_size), goal, shape, self.wrapping)
+            }
+            None => 0,
+        };
+
+        // heap of (estimated total, distance so far, cell)
+        let mut heap = BinaryHeap::new();
+        heap.push(Reverse((heuristic(start_index), 0, start_index)));
+        result.distances[start_index] = Some(0);
+        let mut goal_distance: Option<u64> = None;
+
+        while let Some(Reverse((estimate, distance, current))) = heap.pop() {
+            // stale entry, a shorter route was already found
+            if result.distances[current].is_some_and(|best| distance > best) {
+                continue;
+            }
+
+            // keep going while paths could still tie with the goal, to collect every predecessor
+            if goal_distance.is_some_and(|best| estimate > best) {
+                break;
+            }
+            if goal == Some(result.position_of(current)) {
+                goal_distance = Some(distance);
+                continue;
+            }
+
+            let point = self.point_at(current);
+            for neighbor in neighborhood.neighbors(self, &point) {
+                let Some(step) = cost(&point, &neighbor) else {
+                    continue;
+                };
+
+                let next = neighbor.row * self.row_size + neighbor.col;
+                let next_distance = distance + step;
+                if result.relax(current, next, next_distance) {
+                    heap.push(Reverse((
+                        next_distance + heuristic(next),
+                        next_distance,
+                        next,
+                    )));
+                }
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAZE: &str = "S.#.\n..#.\n....\n#..E";
+
+    fn find(grid: &Grid, value: u8) -> Point {
+        grid.iter_2d().find(|point| point.value == value).unwrap()
+    }
+
+    fn open(_: &Point, to: &Point) -> bool {
+        to.value != b'#'
+    }
+
+    fn unit_cost(_: &Point, to: &Point) -> Option<u64> {
+        (to.value != b'#').then_some(1)
+    }
+
+    #[test]
+    fn test_bfs_distances() {
+        let grid = Grid::new(MAZE);
+        let result = grid.bfs(&find(&grid, b'S'), Neighborhood::Four, open);
+        assert_eq!(result.distance(0, 0), Some(0));
+        assert_eq!(result.distance(3, 3), Some(6));
+        assert_eq!(result.distance(0, 3), Some(7));
+        assert_eq!(result.distance(0, 2), None);
+        assert_eq!(result.distance(9, 9), None);
+    }
+
+    #[test]
+    fn test_bfs_eight_neighbours() {
+        let grid = Grid::new(MAZE);
+        let result = grid.bfs(&find(&grid, b'S'), Neighborhood::Eight, open);
+        assert_eq!(result.distance(3, 3), Some(3));
+    }
+
+    #[test]
+    fn test_path_to() {
+        let grid = Grid::new(MAZE);
+        let result = grid.bfs(&find(&grid, b'S'), Neighborhood::Four, open);
+        let path = result.path_to(3, 3).unwrap();
+        assert_eq!(path.len(), 7);
+        assert_eq!(path.first(), Some(&(0, 0)));
+        assert_eq!(path.last(), Some(&(3, 3)));
+        for step in path.windows(2) {
+            let (a, b) = (step[0], step[1]);
+            assert_eq!(a.0.abs_diff(b.0) + a.1.abs_diff(b.1), 1);
+        }
+    }
+
+    #[test]
+    fn test_all_paths_to() {
+        let grid = Grid::new("...\n...");
+        let start = find(&grid, b'.');
+        let result = grid.bfs(&start, Neighborhood::Four, open);
+        assert_eq!(result.predecessors(1, 1).len(), 2);
+        assert_eq!(result.all_paths_to(1, 2).len(), 3);
+    }
+
+    #[test]
+    fn test_dijkstra_weighted() {
+        // digits are the cost of entering a cell
+        let grid = Grid::new("119\n191\n111");
+        let start = find(&grid, b'1');
+        let result = grid.dijkstra(&start, Neighborhood::Four, |_, to| {
+            Some((to.value - b'0') as u64)
+        });
+        assert_eq!(result.distance(2, 2), Some(4));
+        assert_eq!(result.distance(1, 2), Some(5));
+        assert_eq!(result.all_paths_to(2, 2).len(), 1);
+    }
+
+    #[test]
+    fn test_dijkstra_zero_cost() {
+        // stepping into a `0` is free, including back into the start
+        let grid = Grid::new("00\n01");
+        let start = find(&grid, b'0');
+        let result = grid.dijkstra(&start, Neighborhood::Four, |_, to| {
+            Some((to.value - b'0') as u64)
+        });
+        assert_eq!(result.distance(0, 1), Some(0));
+        assert_eq!(result.distance(1, 1), Some(1));
+        assert!(result.predecessors(0, 0).is_empty());
+        assert_eq!(result.path_to(0, 1), Some(vec![(0, 0), (0, 1)]));
+        assert_eq!(result.all_paths_to(1, 1).len(), 2);
+    }
+
+    #[test]
+    fn test_astar_matches_dijkstra() {
+        let grid = Grid::new(MAZE);
+        let start = find(&grid, b'S');
+        let goal = find(&grid, b'E');
+        let astar = grid.astar(&start, &goal, Neighborhood::Four, unit_cost);
+        let dijkstra = grid.dijkstra(&start, Neighborhood::Four, unit_cost);
+        assert_eq!(astar.distance(3, 3), dijkstra.distance(3, 3));
+        assert_eq!(astar.all_paths_to(3, 3), dijkstra.all_paths_to(3, 3));
+    }
+
+    #[test]
+    fn test_astar_unreachable() {
+        let grid = Grid::new("S#.\n.#E");
+        let start = find(&grid, b'S');
+        let goal = find(&grid, b'E');
+        let result = grid.astar(&start, &goal, Neighborhood::Four, unit_cost);
+        assert_eq!(result.distance(1, 2), None);
+        assert_eq!(result.path_to(1, 2), None);
+    }
+}
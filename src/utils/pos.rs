@@ -0,0 +1,193 @@
+use std::fmt::{self, Display};
+use std::num::ParseIntError;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+use super::grid::{ALL_DIRECTIONS, CARDINAL_DIRECTIONS, Directions, Grid, Point};
+
+// Signed position, x grows to the right and y grows downwards like grid rows
+#[derive(Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub struct Pos {
+    pub x: i64,
+    pub y: i64,
+}
+
+#[allow(dead_code)]
+impl Pos {
+    pub const ORIGIN: Pos = Pos { x: 0, y: 0 };
+
+    pub fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    pub fn manhattan(&self, other: &Pos) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    pub fn step(&self, direction: &Directions) -> Pos {
+        *self + Pos::from(*direction)
+    }
+
+    pub fn neighbors4(&self) -> impl Iterator<Item = Pos> + use<> {
+        let pos = *self;
+        CARDINAL_DIRECTIONS
+            .iter()
+            .map(move |direction| pos.step(direction))
+    }
+
+    pub fn neighbors8(&self) -> impl Iterator<Item = Pos> + use<> {
+        let pos = *self;
+        ALL_DIRECTIONS
+            .iter()
+            .map(move |direction| pos.step(direction))
+    }
+}
+
+impl From<Directions> for Pos {
+    fn from(direction: Directions) -> Self {
+        let (dr, dc) = direction.delta();
+        Pos::new(dc as i64, dr as i64)
+    }
+}
+
+impl<T> From<&Point<T>> for Pos {
+    fn from(point: &Point<T>) -> Self {
+        Pos::new(point.col as i64, point.row as i64)
+    }
+}
+
+impl Add for Pos {
+    type Output = Pos;
+
+    fn add(self, rhs: Pos) -> Pos {
+        Pos::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl Sub for Pos {
+    type Output = Pos;
+
+    fn sub(self, rhs: Pos) -> Pos {
+        Pos::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl Neg for Pos {
+    type Output = Pos;
+
+    fn neg(self) -> Pos {
+        Pos::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<i64> for Pos {
+    type Output = Pos;
+
+    fn mul(self, rhs: i64) -> Pos {
+        Pos::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl AddAssign for Pos {
+    fn add_assign(&mut self, rhs: Pos) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Pos {
+    fn sub_assign(&mut self, rhs: Pos) {
+        *self = *self - rhs;
+    }
+}
+
+impl Display for Pos {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ParsePosError {
+    MissingComma,
+    InvalidNumber(ParseIntError),
+}
+
+impl Display for ParsePosError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParsePosError::MissingComma => write!(f, "expected a position like `x,y`"),
+            ParsePosError::InvalidNumber(err) => write!(f, "invalid coordinate: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ParsePosError {}
+
+// parses `x,y`
+impl FromStr for Pos {
+    type Err = ParsePosError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, y) = s.split_once(',').ok_or(ParsePosError::MissingComma)?;
+        let parse = |num: &str| num.trim().parse().map_err(ParsePosError::InvalidNumber);
+        Ok(Pos::new(parse(x)?, parse(y)?))
+    }
+}
+
+#[allow(dead_code)]
+impl<T> Grid<T> {
+    // get by signed position, anything negative is out of bounds
+    pub fn get_pos(&self, pos: Pos) -> Option<&T> {
+        let row = usize::try_from(pos.y).ok()?;
+        let col = usize::try_from(pos.x).ok()?;
+        self.get(row, col)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vector_arithmetic() {
+        let a = Pos::new(2, -3);
+        let b = Pos::new(-1, 5);
+        assert_eq!(a + b, Pos::new(1, 2));
+        assert_eq!(a - b, Pos::new(3, -8));
+        assert_eq!(-a, Pos::new(-2, 3));
+        assert_eq!(a * 3, Pos::new(6, -9));
+
+        let mut c = a;
+        c += b;
+        c -= Pos::new(1, 1);
+        assert_eq!(c, Pos::new(0, 1));
+    }
+
+    #[test]
+    fn test_step_and_manhattan() {
+        let pos = Pos::ORIGIN
+            .step(&Directions::TopLeft)
+            .step(&Directions::Left);
+        assert_eq!(pos, Pos::new(-2, -1));
+        assert_eq!(pos.manhattan(&Pos::new(1, 1)), 5);
+        assert_eq!(Pos::ORIGIN.neighbors8().count(), 8);
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("7,-3".parse(), Ok(Pos::new(7, -3)));
+        assert_eq!("7".parse::<Pos>(), Err(ParsePosError::MissingComma));
+        assert!(matches!(
+            "a,1".parse::<Pos>(),
+            Err(ParsePosError::InvalidNumber(_))
+        ));
+    }
+
+    #[test]
+    fn test_grid_get_pos() {
+        let grid = Grid::new("ab\ncd");
+        assert_eq!(grid.get_pos(Pos::new(1, 0)), Some(&b'b'));
+        assert_eq!(grid.get_pos(Pos::new(0, 1)), Some(&b'c'));
+        assert_eq!(grid.get_pos(Pos::new(-1, 0)), None);
+    }
+}
@@ -1,15 +1,505 @@
-#[derive(Debug, Copy, Clone)]
-pub struct Range {
-    pub start: i64,
-    pub end: i64,
+use std::fmt::{self, Debug, Display};
+use std::hash::Hash;
+use std::iter::successors;
+use std::num::ParseIntError;
+use std::ops::{Add, Sub};
+use std::str::FromStr;
+
+// Primitive integers a `Range` can hold
+pub trait Integer:
+    Copy
+    + Ord
+    + Debug
+    + Display
+    + Hash
+    + FromStr<Err = ParseIntError>
+    + Add<Output = Self>
+    + Sub<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    // number of decimal digits, anything below 1 counts as a single digit
+    fn digit_count(self) -> u32;
+
+    // 10^exp, None when it doesn't fit
+    fn checked_pow10(exp: u32) -> Option<Self>;
+
+    fn saturating_add(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_integer {
+    ($($type_:ident),*) => {
+        $(
+            impl Integer for $type_ {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn digit_count(self) -> u32 {
+                    self.checked_ilog10().map_or(1, |log| log + 1)
+                }
+
+                fn checked_pow10(exp: u32) -> Option<Self> {
+                    (10 as $type_).checked_pow(exp)
+                }
+
+                fn saturating_add(self, rhs: Self) -> Self {
+                    <$type_>::saturating_add(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+// Inclusive range of integers, `start..=end`
+// Any range with end before start is empty
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Range<T: Integer = i64> {
+    pub start: T,
+    pub end: T,
+}
+
+#[allow(dead_code)]
+impl<T: Integer> Range<T> {
+    // `start..=end`
+    pub fn inclusive(start: T, end: T) -> Self {
+        Self { start, end }
+    }
+
+    // `start..end`
+    pub fn half_open(start: T, end: T) -> Self {
+        if end <= start {
+            return Self::empty();
+        }
+        Self::inclusive(start, end - T::ONE)
+    }
+
+    // 1..=0, which works for unsigned types too
+    pub fn empty() -> Self {
+        Self::inclusive(T::ONE, T::ZERO)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end < self.start
+    }
+
+    // how many numbers are in the range
+    pub fn len(&self) -> T {
+        if self.is_empty() {
+            return T::ZERO;
+        }
+        self.end - self.start + T::ONE
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    pub fn overlaps(&self, other: &Range<T>) -> bool {
+        !self.clamp(other).is_empty()
+    }
+
+    // the part of the range inside `bounds`
+    pub fn clamp(&self, bounds: &Range<T>) -> Self {
+        let clamped = Self::inclusive(self.start.max(bounds.start), self.end.min(bounds.end));
+        if clamped.is_empty() {
+            return Self::empty();
+        }
+        clamped
+    }
+
+    // numbers below `at`, and numbers from `at` up, either can be empty
+    pub fn split_at(&self, at: T) -> (Self, Self) {
+        let below = match at > self.start {
+            true => Self::inclusive(self.start, self.end.min(at - T::ONE)),
+            false => Self::empty(),
+        };
+        let from = match at <= self.end {
+            true => Self::inclusive(self.start.max(at), self.end),
+            false => Self::empty(),
+        };
+        (below, from)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = T> + use<T> {
+        let end = self.end;
+        let first = (!self.is_empty()).then_some(self.start);
+        successors(first, move |&n| (n < end).then(|| n + T::ONE))
+    }
+
+    // consecutive pieces that each hold numbers with the same number of digits
+    // e.g. 95..=1012 becomes 95..=99, 100..=999 and 1000..=1012
+    // negative numbers aren't split by digits, everything below 0 is a single piece
+    pub fn split_by_digit_count(&self) -> Vec<Self> {
+        let mut pieces = Vec::new();
+        let (negative, mut rest) = self.split_at(T::ZERO);
+        if !negative.is_empty() {
+            pieces.push(negative);
+        }
+
+        while !rest.is_empty() {
+            let digits = rest.start.digit_count();
+            match T::checked_pow10(digits) {
+                Some(boundary) => {
+                    let (piece, next) = rest.split_at(boundary);
+                    pieces.push(piece);
+                    rest = next;
+                }
+                // no bigger number of digits fits in the type
+                None => {
+                    pieces.push(rest);
+                    break;
+                }
+            }
+        }
+
+        pieces
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ParseRangeError {
+    MissingSeparator(String),
+    InvalidNumber(ParseIntError),
 }
 
-impl From<&str> for Range {
-    fn from(value: &str) -> Self {
-        let parts: Vec<&str> = value.split('-').collect();
-        Self {
-            start: parts[0].trim().parse().unwrap(),
-            end: parts[1].trim().parse().unwrap(),
+impl Display for ParseRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseRangeError::MissingSeparator(input) => {
+                write!(f, "expected `start-end`, found `{input}`")
+            }
+            ParseRangeError::InvalidNumber(err) => write!(f, "invalid bound: {err}"),
         }
     }
 }
+
+impl std::error::Error for ParseRangeError {}
+
+// parses `start-end`, either bound can be negative like `-5--2`
+impl<T: Integer> FromStr for Range<T> {
+    type Err = ParseRangeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        // a `-` at the very start is the sign of `start`, not the separator
+        let separator = s
+            .char_indices()
+            .skip(1)
+            .find(|&(_, c)| c == '-')
+            .map(|(i, _)| i)
+            .ok_or_else(|| ParseRangeError::MissingSeparator(s.to_string()))?;
+
+        let parse = |bound: &str| bound.trim().parse().map_err(ParseRangeError::InvalidNumber);
+        Ok(Self::inclusive(
+            parse(&s[..separator])?,
+            parse(&s[separator + 1..])?,
+        ))
+    }
+}
+
+// Set of integers stored as sorted, disjoint ranges
+// Ranges that overlap or touch are always merged, so every gap is at least one number wide
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeSet<T: Integer = i64> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T: Integer> Default for RangeSet<T> {
+    fn default() -> Self {
+        Self { ranges: Vec::new() }
+    }
+}
+
+#[allow(dead_code)]
+impl<T: Integer> RangeSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // the disjoint ranges, in order
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    // how many numbers are in the set
+    pub fn total_len(&self) -> T {
+        self.ranges
+            .iter()
+            .fold(T::ZERO, |total, range| total + range.len())
+    }
+
+    // binary search for the only range that could hold `value`
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.ranges.partition_point(|range| range.end < value);
+        self.ranges.get(i).is_some_and(|range| range.start <= value)
+    }
+
+    // add every number in `range`, merging with any range it overlaps or touches
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+
+        // ranges from `first` up to `last` overlap `range` or sit right next to it
+        let first = self
+            .ranges
+            .partition_point(|r| r.end.saturating_add(T::ONE) < range.start);
+        let last = self
+            .ranges
+            .partition_point(|r| r.start <= range.end.saturating_add(T::ONE));
+
+        let mut merged = range;
+        if first < last {
+            merged.start = merged.start.min(self.ranges[first].start);
+            merged.end = merged.end.max(self.ranges[last - 1].end);
+        }
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    // take every number in `range` out of the set, splitting a range if needed
+    pub fn remove(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+
+        let first = self.ranges.partition_point(|r| r.end < range.start);
+        let last = self.ranges.partition_point(|r| r.start <= range.end);
+        if first == last {
+            return;
+        }
+
+        // whatever sticks out either side of the removed range survives
+        let mut left_over = Vec::with_capacity(2);
+        let (head, tail) = (self.ranges[first], self.ranges[last - 1]);
+        if head.start < range.start {
+            left_over.push(Range::inclusive(head.start, range.start - T::ONE));
+        }
+        if tail.end > range.end {
+            left_over.push(Range::inclusive(range.end + T::ONE, tail.end));
+        }
+        self.ranges.splice(first..last, left_over);
+    }
+
+    pub fn union(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        let mut union = self.clone();
+        for &range in &other.ranges {
+            union.insert(range);
+        }
+        union
+    }
+
+    // numbers in both sets, walking both lists of ranges together
+    pub fn intersect(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let overlap = Range::inclusive(a.start.max(b.start), a.end.min(b.end));
+            if !overlap.is_empty() {
+                ranges.push(overlap);
+            }
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        RangeSet { ranges }
+    }
+
+    // numbers in this set but not in `other`
+    pub fn subtract(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        let mut difference = self.clone();
+        for &range in &other.ranges {
+            difference.remove(range);
+        }
+        difference
+    }
+
+    // numbers within `bounds` that aren't in the set
+    pub fn complement(&self, bounds: Range<T>) -> RangeSet<T> {
+        let mut complement = RangeSet::new();
+        complement.insert(bounds);
+        complement.subtract(self)
+    }
+}
+
+impl<T: Integer> FromIterator<Range<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        let mut set = RangeSet::new();
+        for range in iter {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[(i64, i64)]) -> RangeSet {
+        ranges
+            .iter()
+            .map(|&(start, end)| Range::inclusive(start, end))
+            .collect()
+    }
+
+    fn pairs(set: &RangeSet) -> Vec<(i64, i64)> {
+        set.ranges().iter().map(|r| (r.start, r.end)).collect()
+    }
+
+    #[test]
+    fn test_range_constructors() {
+        assert_eq!(Range::half_open(3, 7), Range::inclusive(3, 6));
+        assert!(Range::<u32>::half_open(0, 0).is_empty());
+        assert_eq!(Range::<u8>::half_open(5, 2).len(), 0);
+        assert_eq!(Range::inclusive(-2, 2).len(), 5);
+    }
+
+    #[test]
+    fn test_range_parse() {
+        assert_eq!("10-14".parse(), Ok(Range::inclusive(10, 14)));
+        assert_eq!(" -5--2 ".parse(), Ok(Range::inclusive(-5, -2)));
+        assert_eq!("-3-4".parse(), Ok(Range::inclusive(-3, 4)));
+        assert_eq!(
+            "12".parse::<Range>(),
+            Err(ParseRangeError::MissingSeparator("12".to_string()))
+        );
+        assert!(matches!(
+            "1-x".parse::<Range>(),
+            Err(ParseRangeError::InvalidNumber(_))
+        ));
+        assert!("-1-2".parse::<Range<u8>>().is_err());
+    }
+
+    #[test]
+    fn test_range_overlap_clamp_and_split() {
+        let range = Range::inclusive(10u64, 20);
+        assert!(range.overlaps(&Range::inclusive(20, 30)));
+        assert!(!range.overlaps(&Range::inclusive(21, 30)));
+        assert_eq!(
+            range.clamp(&Range::inclusive(15, 30)),
+            Range::inclusive(15, 20)
+        );
+        assert!(range.clamp(&Range::inclusive(0, 5)).is_empty());
+
+        assert_eq!(
+            range.split_at(15),
+            (Range::inclusive(10, 14), Range::inclusive(15, 20))
+        );
+        let (below, from) = range.split_at(10);
+        assert!(below.is_empty());
+        assert_eq!(from, range);
+    }
+
+    #[test]
+    fn test_range_iter() {
+        assert_eq!(
+            Range::inclusive(3, 6).iter().collect::<Vec<i64>>(),
+            vec![3, 4, 5, 6]
+        );
+        assert_eq!(Range::<u8>::inclusive(254, 255).iter().count(), 2);
+        assert_eq!(Range::<i64>::empty().iter().count(), 0);
+    }
+
+    #[test]
+    fn test_split_by_digit_count() {
+        assert_eq!(
+            Range::inclusive(95, 1012).split_by_digit_count(),
+            vec![
+                Range::inclusive(95, 99),
+                Range::inclusive(100, 999),
+                Range::inclusive(1000, 1012),
+            ]
+        );
+        assert_eq!(
+            Range::<u8>::inclusive(5, 255).split_by_digit_count(),
+            vec![
+                Range::inclusive(5, 9),
+                Range::inclusive(10, 99),
+                Range::inclusive(100, 255),
+            ]
+        );
+        // the negative part stays in one piece
+        assert_eq!(
+            Range::inclusive(-250, 12).split_by_digit_count(),
+            vec![
+                Range::inclusive(-250, -1),
+                Range::inclusive(0, 9),
+                Range::inclusive(10, 12),
+            ]
+        );
+        assert_eq!(
+            Range::inclusive(-20, -3).split_by_digit_count(),
+            vec![Range::inclusive(-20, -3)]
+        );
+    }
+
+    #[test]
+    fn test_insert_merges_overlapping_and_touching() {
+        let mut ranges = set(&[(3, 5), (10, 14), (16, 20), (12, 18)]);
+        assert_eq!(pairs(&ranges), vec![(3, 5), (10, 20)]);
+        assert_eq!(ranges.total_len(), 14);
+
+        ranges.insert(Range::inclusive(6, 9));
+        assert_eq!(pairs(&ranges), vec![(3, 20)]);
+        ranges.insert(Range::inclusive(30, 29));
+        assert_eq!(pairs(&ranges), vec![(3, 20)]);
+    }
+
+    #[test]
+    fn test_contains() {
+        let ranges = set(&[(3, 5), (10, 14), (16, 20)]);
+        let found: Vec<i64> = (0..25).filter(|&n| ranges.contains(n)).collect();
+        assert_eq!(found, vec![3, 4, 5, 10, 11, 12, 13, 14, 16, 17, 18, 19, 20]);
+    }
+
+    #[test]
+    fn test_remove_splits() {
+        let mut ranges = set(&[(0, 10), (20, 30)]);
+        ranges.remove(Range::inclusive(5, 22));
+        assert_eq!(pairs(&ranges), vec![(0, 4), (23, 30)]);
+        ranges.remove(Range::inclusive(0, 4));
+        assert_eq!(pairs(&ranges), vec![(23, 30)]);
+        ranges.remove(Range::inclusive(25, 26));
+        assert_eq!(pairs(&ranges), vec![(23, 24), (27, 30)]);
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = set(&[(0, 10), (20, 30)]);
+        let b = set(&[(5, 25)]);
+        assert_eq!(pairs(&a.union(&b)), vec![(0, 30)]);
+        assert_eq!(pairs(&a.intersect(&b)), vec![(5, 10), (20, 25)]);
+        assert_eq!(pairs(&a.subtract(&b)), vec![(0, 4), (26, 30)]);
+        assert_eq!(
+            pairs(&a.complement(Range::inclusive(-5, 35))),
+            vec![(-5, -1), (11, 19), (31, 35)]
+        );
+    }
+
+    #[test]
+    fn test_unsigned_range_set() {
+        let mut ranges: RangeSet<u8> = [Range::inclusive(250, 255), Range::inclusive(0, 3)]
+            .into_iter()
+            .collect();
+        ranges.insert(Range::inclusive(4, 9));
+        assert_eq!(
+            ranges.ranges(),
+            [Range::inclusive(0, 9), Range::inclusive(250, 255)]
+        );
+        assert_eq!(ranges.total_len(), 16);
+        assert!(ranges.contains(255));
+        ranges.remove(Range::inclusive(0, 0));
+        assert_eq!(ranges.ranges()[0], Range::inclusive(1, 9));
+    }
+}
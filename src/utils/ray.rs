@@ -0,0 +1,174 @@
+use std::iter::{once, successors};
+
+use super::grid::{Directions, Grid, Point};
+
+#[allow(dead_code)]
+impl<T: Copy> Grid<T> {
+    // every point walking from `point` in `direction` until the edge, not including `point`
+    // on a wrapping grid the ray stops once it comes back around to `point`
+    pub fn ray(
+        &self,
+        point: &Point<T>,
+        direction: &Directions,
+    ) -> impl Iterator<Item = Point<T>> + use<'_, T> {
+        let direction = *direction;
+        let start = (point.row, point.col);
+        successors(self.get_point_in_direction(point, &direction), move |p| {
+            self.get_point_in_direction(p, &direction)
+        })
+        .take_while(move |p| (p.row, p.col) != start)
+    }
+
+    // first point along the ray matching `predicate`
+    pub fn first_in_direction<F>(
+        &self,
+        point: &Point<T>,
+        direction: &Directions,
+        predicate: F,
+    ) -> Option<Point<T>>
+    where
+        F: Fn(&Point<T>) -> bool,
+    {
+        self.ray(point, direction).find(|p| predicate(p))
+    }
+
+    // does `pattern` read out starting at `point` and walking in `direction`
+    pub fn matches_in_direction(
+        &self,
+        point: &Point<T>,
+        direction: &Directions,
+        pattern: &[T],
+    ) -> bool
+    where
+        T: PartialEq,
+    {
+        let cells = once(*point).chain(self.ray(point, direction));
+        let mut matched = 0;
+        for (cell, expected) in cells.zip(pattern) {
+            if cell.value != *expected {
+                return false;
+            }
+            matched += 1;
+        }
+
+        matched == pattern.len()
+    }
+
+    // diagonals running top left to bottom right, starting from the bottom left corner
+    pub fn iter_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = Point<T>>> {
+        let down_first_col = (0..self.num_rows()).rev().map(|row| (row, 0));
+        let along_first_row = (1..self.row_size).map(|col| (0, col));
+
+        down_first_col
+            .chain(along_first_row)
+            .map(move |(row, col)| self.line_from(row, col, Directions::BottomRight))
+    }
+
+    // diagonals running top right to bottom left, starting from the top left corner
+    pub fn iter_antidiagonals(&self) -> impl Iterator<Item = impl Iterator<Item = Point<T>>> {
+        let last_col = self.row_size.saturating_sub(1);
+        let along_first_row = (0..self.row_size).map(|col| (0, col));
+        let down_last_col = (1..self.num_rows()).map(move |row| (row, last_col));
+
+        along_first_row
+            .chain(down_last_col)
+            .map(move |(row, col)| self.line_from(row, col, Directions::BottomLeft))
+    }
+
+    // the point at (row, col) and every point after it in `direction` up to the edge
+    // walks the bounds directly so it never wraps, even on a wrapping grid
+    fn line_from(
+        &self,
+        row: usize,
+        col: usize,
+        direction: Directions,
+    ) -> impl Iterator<Item = Point<T>> {
+        let (d_row, d_col) = direction.delta();
+        successors(Some((row, col)), move |&(row, col)| {
+            let row = row.checked_add_signed(d_row)?;
+            let col = col.checked_add_signed(d_col)?;
+            (row < self.num_rows() && col < self.row_size).then_some((row, col))
+        })
+        .map(move |(row, col)| Point {
+            row,
+            col,
+            value: self[(row, col)],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::grid::ALL_DIRECTIONS;
+
+    fn values(points: impl Iterator<Item = Point>) -> String {
+        points.map(|p| p.value as char).collect()
+    }
+
+    #[test]
+    fn test_ray_stops_at_edge() {
+        let grid = Grid::new("abc\ndef\nghi");
+        let start = grid.iter_2d().next().unwrap();
+        assert_eq!(values(grid.ray(&start, &Directions::Right)), "bc");
+        assert_eq!(values(grid.ray(&start, &Directions::BottomRight)), "ei");
+        assert_eq!(values(grid.ray(&start, &Directions::Top)), "");
+    }
+
+    #[test]
+    fn test_ray_wraps_once_around() {
+        let grid = Grid::new("abc\ndef\nghi").with_wrapping(true, true);
+        let start = grid.iter_2d().nth(4).unwrap();
+        assert_eq!(values(grid.ray(&start, &Directions::Right)), "fd");
+        assert_eq!(values(grid.ray(&start, &Directions::TopLeft)), "ai");
+    }
+
+    #[test]
+    fn test_first_in_direction() {
+        let grid = Grid::new("a..#.#");
+        let start = grid.iter_2d().next().unwrap();
+        let found = grid.first_in_direction(&start, &Directions::Right, |p| p.value == b'#');
+        assert_eq!(found.map(|p| p.col), Some(3));
+        let missing = grid.first_in_direction(&start, &Directions::Left, |p| p.value == b'#');
+        assert_eq!(missing, None);
+    }
+
+    #[test]
+    fn test_iter_diagonals() {
+        let grid = Grid::new("abc\ndef");
+        let diagonals: Vec<String> = grid.iter_diagonals().map(values).collect();
+        assert_eq!(diagonals, vec!["d", "ae", "bf", "c"]);
+    }
+
+    #[test]
+    fn test_iter_antidiagonals() {
+        let grid = Grid::new("abc\ndef");
+        let antidiagonals: Vec<String> = grid.iter_antidiagonals().map(values).collect();
+        assert_eq!(antidiagonals, vec!["a", "bd", "ce", "f"]);
+    }
+
+    #[test]
+    fn test_diagonals_ignore_wrapping() {
+        let grid = Grid::new("abc\ndef").with_wrapping(true, true);
+        let diagonals: Vec<String> = grid.iter_diagonals().map(values).collect();
+        assert_eq!(diagonals, vec!["d", "ae", "bf", "c"]);
+        let antidiagonals: Vec<String> = grid.iter_antidiagonals().map(values).collect();
+        assert_eq!(antidiagonals, vec!["a", "bd", "ce", "f"]);
+    }
+
+    #[test]
+    fn test_word_search_in_all_directions() {
+        let input = "MMMSXXMASM\nMSAMXMSMSA\nAMXSXMAAMM\nMSAMASMSMX\nXMASAMXAMM\nXXAMMXXAMA\nSMSMSASXSS\nSAXAMASAAA\nMAMMMXMMMM\nMXMXAXMASX";
+        let grid = Grid::new(input);
+        let count = grid
+            .iter_2d()
+            .flat_map(|point| {
+                ALL_DIRECTIONS
+                    .iter()
+                    .map(move |direction| (point, direction))
+            })
+            .filter(|(point, direction)| grid.matches_in_direction(point, direction, b"XMAS"))
+            .count();
+        assert_eq!(count, 18);
+    }
+}
@@ -0,0 +1,219 @@
+use super::grid::{CARDINAL_DIRECTIONS, Directions, Grid, Point};
+use super::pathfinding::Neighborhood;
+
+// Pairs of sides meeting at each corner of a cell, with the diagonal between them
+const CORNERS: [(Directions, Directions, Directions); 4] = [
+    (Directions::Top, Directions::Right, Directions::TopRight),
+    (
+        Directions::Right,
+        Directions::Bottom,
+        Directions::BottomRight,
+    ),
+    (Directions::Bottom, Directions::Left, Directions::BottomLeft),
+    (Directions::Left, Directions::Top, Directions::TopLeft),
+];
+
+#[derive(Debug, PartialEq)]
+pub struct Region {
+    pub label: usize,
+    // (row, col) of every cell, in the order they were found
+    pub cells: Vec<(usize, usize)>,
+    pub area: usize,
+    pub perimeter: usize,
+    // number of straight sides, which is always the same as the number of corners
+    pub sides: usize,
+}
+
+#[derive(Debug)]
+pub struct Components {
+    // region label of every cell
+    pub labels: Grid<usize>,
+    pub regions: Vec<Region>,
+}
+
+#[allow(dead_code)]
+impl<T: Copy> Grid<T> {
+    // every cell 4-connected to the start where `predicate` holds, including the start itself
+    pub fn flood_fill<F>(&self, start: &Point<T>, predicate: F) -> Vec<Point<T>>
+    where
+        F: Fn(&Point<T>) -> bool,
+    {
+        if !predicate(start) {
+            return Vec::new();
+        }
+
+        let search = self.bfs(start, Neighborhood::Four, |_, to| predicate(to));
+        self.iter_2d()
+            .filter(|point| search.distance(point.row, point.col).is_some())
+            .collect()
+    }
+
+    // split the grid into 4-connected regions
+    // `connected(a, b)` decides if two neighbouring cells belong to the same region
+    pub fn components<F>(&self, connected: F) -> Components
+    where
+        F: Fn(&Point<T>, &Point<T>) -> bool,
+    {
+        let mut labels: Grid<Option<usize>> = Grid {
+            data: vec![None; self.data.len()],
+            row_size: self.row_size,
+            wrapping: self.wrapping,
+        };
+        let mut regions: Vec<Vec<(usize, usize)>> = Vec::new();
+
+        for start in self.iter_2d() {
+            if labels[(start.row, start.col)].is_some() {
+                continue;
+            }
+
+            let label = regions.len();
+            labels[(start.row, start.col)] = Some(label);
+            let mut cells = Vec::new();
+            let mut stack = vec![start];
+
+            while let Some(point) = stack.pop() {
+                cells.push((point.row, point.col));
+
+                for neighbor in self.neighbors4(&point) {
+                    if labels[(neighbor.row, neighbor.col)].is_some()
+                        || !connected(&point, &neighbor)
+                    {
+                        continue;
+                    }
+
+                    labels[(neighbor.row, neighbor.col)] = Some(label);
+                    stack.push(neighbor);
+                }
+            }
+
+            regions.push(cells);
+        }
+
+        let labels = Grid {
+            data: labels.data.into_iter().map(Option::unwrap).collect(),
+            row_size: self.row_size,
+            wrapping: self.wrapping,
+        };
+
+        let regions = regions
+            .into_iter()
+            .enumerate()
+            .map(|(label, cells)| {
+                let (perimeter, sides) = measure_region(&labels, &cells);
+                Region {
+                    label,
+                    area: cells.len(),
+                    cells,
+                    perimeter,
+                    sides,
+                }
+            })
+            .collect();
+
+        Components { labels, regions }
+    }
+}
+
+// perimeter and side count, from edges facing other regions and from corners
+fn measure_region(labels: &Grid<usize>, cells: &[(usize, usize)]) -> (usize, usize) {
+    let mut perimeter = 0;
+    let mut corners = 0;
+
+    for &(row, col) in cells {
+        let point = Point {
+            row,
+            col,
+            value: labels[(row, col)],
+        };
+        let same_region = |direction: &Directions| {
+            labels
+                .get_point_in_direction(&point, direction)
+                .is_some_and(|neighbor| neighbor.value == point.value)
+        };
+
+        perimeter += CARDINAL_DIRECTIONS
+            .iter()
+            .filter(|direction| !same_region(direction))
+            .count();
+
+        for (a, b, diagonal) in &CORNERS {
+            let outer = !same_region(a) && !same_region(b);
+            let inner = same_region(a) && same_region(b) && !same_region(diagonal);
+            if outer || inner {
+                corners += 1;
+            }
+        }
+    }
+
+    (perimeter, corners)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn same_value(a: &Point, b: &Point) -> bool {
+        a.value == b.value
+    }
+
+    #[test]
+    fn test_flood_fill() {
+        let grid = Grid::new("..#\n.##\n#..");
+        let start = grid.iter_2d().next().unwrap();
+        let filled = grid.flood_fill(&start, |point| point.value == b'.');
+        let positions: Vec<(usize, usize)> = filled.iter().map(|p| (p.row, p.col)).collect();
+        assert_eq!(positions, vec![(0, 0), (0, 1), (1, 0)]);
+    }
+
+    #[test]
+    fn test_flood_fill_start_not_matching() {
+        let grid = Grid::new("..#\n.##\n#..");
+        let start = grid.iter_2d().nth(2).unwrap();
+        assert!(
+            grid.flood_fill(&start, |point| point.value == b'.')
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_components_small_example() {
+        let grid = Grid::new("AAAA\nBBCD\nBBCC\nEEEC");
+        let components = grid.components(same_value);
+        let stats: Vec<(u8, usize, usize, usize)> = components
+            .regions
+            .iter()
+            .map(|region| {
+                let (row, col) = region.cells[0];
+                (
+                    grid[(row, col)],
+                    region.area,
+                    region.perimeter,
+                    region.sides,
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            stats,
+            vec![
+                (b'A', 4, 10, 4),
+                (b'B', 4, 8, 4),
+                (b'C', 4, 10, 8),
+                (b'D', 1, 4, 4),
+                (b'E', 3, 8, 4),
+            ]
+        );
+        assert_eq!(components.labels[(3, 3)], components.labels[(1, 2)]);
+    }
+
+    #[test]
+    fn test_components_region_with_holes() {
+        let grid = Grid::new("OOOOO\nOXOXO\nOOOOO\nOXOXO\nOOOOO");
+        let components = grid.components(same_value);
+        let outer = &components.regions[0];
+        assert_eq!(outer.area, 21);
+        assert_eq!(outer.perimeter, 36);
+        assert_eq!(outer.sides, 20);
+        assert_eq!(components.regions.len(), 5);
+    }
+}
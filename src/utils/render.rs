@@ -0,0 +1,222 @@
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+
+use super::grid::Grid;
+
+const RESET: &str = "\x1b[0m";
+// bold and reversed, stands out on top of any region colour
+const MARK_STYLE: &str = "\x1b[1;7m";
+// background colours for region labels, red through cyan
+const REGION_STYLES: [&str; 6] = [
+    "\x1b[41m", "\x1b[42m", "\x1b[43m", "\x1b[44m", "\x1b[45m", "\x1b[46m",
+];
+
+// write `glyph` in `style`, or as plain text when colour is off so the output can be
+// compared in tests and saved to files
+pub(super) fn write_styled(
+    f: &mut fmt::Formatter<'_>,
+    glyph: char,
+    style: Option<&str>,
+    color: bool,
+) -> fmt::Result {
+    match style {
+        Some(style) if color => write!(f, "{style}{glyph}{RESET}"),
+        _ => write!(f, "{glyph}"),
+    }
+}
+
+// Debug view of a grid with marked cells, coloured regions and rulers
+// Build with `Grid::render` and print it, colour is on unless turned off
+pub struct Render<'a, T> {
+    grid: &'a Grid<T>,
+    render_cell: Box<dyn Fn(&T) -> char + 'a>,
+    marks: HashMap<(usize, usize), char>,
+    labels: Option<&'a Grid<usize>>,
+    rulers: bool,
+    color: bool,
+}
+
+#[allow(dead_code)]
+impl Grid {
+    pub fn render(&self) -> Render<'_, u8> {
+        self.render_with(|&cell| cell as char)
+    }
+}
+
+#[allow(dead_code)]
+impl<T> Grid<T> {
+    // like `render`, with each cell drawn as the char from `render_cell`
+    pub fn render_with<'a, F>(&'a self, render_cell: F) -> Render<'a, T>
+    where
+        F: Fn(&T) -> char + 'a,
+    {
+        Render {
+            grid: self,
+            render_cell: Box::new(render_cell),
+            marks: HashMap::new(),
+            labels: None,
+            rulers: false,
+            color: true,
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl<'a, T> Render<'a, T> {
+    // draw `glyph` over every (row, col), later marks win
+    pub fn mark<I>(mut self, points: I, glyph: char) -> Self
+    where
+        I: IntoIterator<Item = (usize, usize)>,
+    {
+        for point in points {
+            self.marks.insert(point, glyph);
+        }
+        self
+    }
+
+    // draw arrows along a path of (row, col) steps, the last cell gets `*`
+    pub fn path(mut self, path: &[(usize, usize)]) -> Self {
+        for step in path.windows(2) {
+            let ((r0, c0), (r1, c1)) = (step[0], step[1]);
+            let arrow = match (r1 as isize - r0 as isize, c1 as isize - c0 as isize) {
+                (-1, 0) => '^',
+                (1, 0) => 'v',
+                (0, -1) => '<',
+                (0, 1) => '>',
+                _ => '*',
+            };
+            self.marks.insert(step[0], arrow);
+        }
+        if let Some(&last) = path.last() {
+            self.marks.insert(last, '*');
+        }
+        self
+    }
+
+    // colour the background of every cell by its region label, e.g. from `Grid::components`
+    pub fn regions(mut self, labels: &'a Grid<usize>) -> Self {
+        self.labels = Some(labels);
+        self
+    }
+
+    // column numbers above and row numbers to the left
+    pub fn rulers(mut self) -> Self {
+        self.rulers = true;
+        self
+    }
+
+    // when off mark glyphs still show but regions are lost
+    pub fn color(mut self, on: bool) -> Self {
+        self.color = on;
+        self
+    }
+
+    fn style_of(&self, row: usize, col: usize) -> Option<&'static str> {
+        if self.marks.contains_key(&(row, col)) {
+            return Some(MARK_STYLE);
+        }
+        let label = self.labels?.get(row, col)?;
+        Some(REGION_STYLES[label % REGION_STYLES.len()])
+    }
+
+    fn write_column_ruler(&self, f: &mut fmt::Formatter<'_>, margin: usize) -> fmt::Result {
+        let row_size = self.grid.row_size;
+        // tens on a line of their own, only once there are enough columns to need them
+        if row_size > 10 {
+            write!(f, "{:margin$}", "")?;
+            for col in 0..row_size {
+                match col % 10 {
+                    0 => write!(f, "{}", col / 10 % 10)?,
+                    _ => write!(f, " ")?,
+                }
+            }
+            writeln!(f)?;
+        }
+
+        write!(f, "{:margin$}", "")?;
+        for col in 0..row_size {
+            write!(f, "{}", col % 10)?;
+        }
+        writeln!(f)
+    }
+}
+
+impl<T> Display for Render<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // room for the widest row number and a space
+        let margin = match self.rulers {
+            true => self.grid.num_rows().saturating_sub(1).to_string().len() + 1,
+            false => 0,
+        };
+
+        if self.rulers {
+            self.write_column_ruler(f, margin)?;
+        }
+
+        for (row, cells) in self.grid.iter_rows().enumerate() {
+            if self.rulers {
+                write!(f, "{row:>width$} ", width = margin - 1)?;
+            }
+
+            for (col, cell) in cells.iter().enumerate() {
+                let glyph = match self.marks.get(&(row, col)) {
+                    Some(&glyph) => glyph,
+                    None => (self.render_cell)(cell),
+                };
+                write_styled(f, glyph, self.style_of(row, col), self.color)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plain_render_matches_display() {
+        let grid = Grid::new("ab\ncd");
+        assert_eq!(grid.render().color(false).to_string(), grid.to_string());
+    }
+
+    #[test]
+    fn test_marks_and_path() {
+        let grid = Grid::new("....\n.##.\n....");
+        let rendered = grid
+            .render()
+            .mark([(1, 1)], 'X')
+            .path(&[(0, 0), (0, 1), (0, 2), (0, 3), (1, 3), (2, 3)])
+            .color(false)
+            .to_string();
+        assert_eq!(rendered, ">>>v\n.X#v\n...*\n");
+    }
+
+    #[test]
+    fn test_rulers() {
+        let grid = Grid::new(&["............"; 11].join("\n"));
+        let rendered = grid.render().rulers().color(false).to_string();
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(lines[0], "   0         1 ");
+        assert_eq!(lines[1], "   012345678901");
+        assert_eq!(lines[2], " 0 ............");
+        assert_eq!(lines[12], "10 ............");
+    }
+
+    #[test]
+    fn test_colour_regions_and_marks() {
+        let grid = Grid::new("ab");
+        let labels = grid.components(|a, b| a.value == b.value).labels;
+        let rendered = grid
+            .render()
+            .regions(&labels)
+            .mark([(0, 1)], '!')
+            .to_string();
+        assert_eq!(rendered, "\x1b[41ma\x1b[0m\x1b[1;7m!\x1b[0m\n");
+
+        let plain = grid.render().regions(&labels).color(false).to_string();
+        assert_eq!(plain, "ab\n");
+    }
+}
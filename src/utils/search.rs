@@ -0,0 +1,156 @@
+use super::grid::{Grid, Point};
+
+// How a template was turned before it matched, the flip is applied first
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Orientation {
+    // mirrored left to right
+    pub flipped: bool,
+    // quarter turns clockwise, 0 to 3
+    pub rotations: u8,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PatternMatch {
+    // top left corner of the template in the grid
+    pub row: usize,
+    pub col: usize,
+    pub orientation: Orientation,
+}
+
+#[allow(dead_code)]
+impl<T: Copy + PartialEq> Grid<T> {
+    // first cell holding `value`, row by row
+    pub fn find(&self, value: T) -> Option<Point<T>> {
+        self.iter_2d().find(|point| point.value == value)
+    }
+
+    pub fn find_all(&self, value: T) -> Vec<Point<T>> {
+        self.iter_2d()
+            .filter(|point| point.value == value)
+            .collect()
+    }
+
+    // (row, col) of every cell where `predicate` holds
+    pub fn positions_where<F>(&self, predicate: F) -> Vec<(usize, usize)>
+    where
+        F: Fn(&Point<T>) -> bool,
+    {
+        self.iter_2d()
+            .filter(|point| predicate(point))
+            .map(|point| (point.row, point.col))
+            .collect()
+    }
+
+    // every placement of `template` in any of its 8 rotations and reflections
+    // cells of the template holding `wildcard` match anything
+    // a symmetric template only counts each distinct orientation once
+    pub fn match_pattern(&self, template: &Grid<T>, wildcard: T) -> Vec<PatternMatch> {
+        let mut orientations: Vec<(Orientation, Grid<T>)> = Vec::new();
+        for flipped in [false, true] {
+            let mut turned = match flipped {
+                true => template.flip_h(),
+                false => template.clone(),
+            };
+            for rotations in 0..4 {
+                if !orientations.iter().any(|(_, seen)| *seen == turned) {
+                    let orientation = Orientation { flipped, rotations };
+                    orientations.push((orientation, turned.clone()));
+                }
+                turned = turned.rotate_cw();
+            }
+        }
+
+        let mut matches = Vec::new();
+        for (orientation, pattern) in &orientations {
+            for (row, col) in self.placements(pattern) {
+                if self.matches_at(pattern, row, col, wildcard) {
+                    matches.push(PatternMatch {
+                        row,
+                        col,
+                        orientation: *orientation,
+                    });
+                }
+            }
+        }
+        matches
+    }
+
+    // every top left corner where `pattern` fits inside the grid
+    fn placements(&self, pattern: &Grid<T>) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        let rows = (self.num_rows() + 1).saturating_sub(pattern.num_rows());
+        let cols = (self.row_size + 1).saturating_sub(pattern.row_size);
+        (0..rows).flat_map(move |row| (0..cols).map(move |col| (row, col)))
+    }
+
+    fn matches_at(&self, pattern: &Grid<T>, row: usize, col: usize, wildcard: T) -> bool {
+        pattern.iter_2d().all(|cell| {
+            cell.value == wildcard || self[(row + cell.row, col + cell.col)] == cell.value
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find() {
+        let grid = Grid::new("#.S\n.E.\nS..");
+        let start = grid.find(b'S').unwrap();
+        assert_eq!((start.row, start.col), (0, 2));
+        assert_eq!(grid.find(b'X'), None);
+
+        let starts: Vec<(usize, usize)> =
+            grid.find_all(b'S').iter().map(|p| (p.row, p.col)).collect();
+        assert_eq!(starts, vec![(0, 2), (2, 0)]);
+        assert_eq!(
+            grid.positions_where(|p| p.value != b'.'),
+            vec![(0, 0), (0, 2), (1, 1), (2, 0)]
+        );
+    }
+
+    #[test]
+    fn test_x_shaped_word_search() {
+        let input = "MMMSXXMASM\nMSAMXMSMSA\nAMXSXMAAMM\nMSAMASMSMX\nXMASAMXAMM\nXXAMMXXAMA\nSMSMSASXSS\nSAXAMASAAA\nMAMMMXMMMM\nMXMXAXMASX";
+        let grid = Grid::new(input);
+        let template = Grid::new("M.S\n.A.\nM.S");
+        assert_eq!(grid.match_pattern(&template, b'.').len(), 9);
+    }
+
+    #[test]
+    fn test_symmetric_template_counts_once() {
+        let grid = Grid::new("....\n.##.\n.##.\n....");
+        let square = Grid::new("##\n##");
+        let matches = grid.match_pattern(&square, b'?');
+        assert_eq!(
+            matches,
+            vec![PatternMatch {
+                row: 1,
+                col: 1,
+                orientation: Orientation {
+                    flipped: false,
+                    rotations: 0
+                },
+            }]
+        );
+    }
+
+    #[test]
+    fn test_rotated_shape() {
+        let grid = Grid::new("....\n.#..\n.###\n....");
+        // an L that only fits after three quarter turns
+        let shape = Grid::new("##\n#.\n#.");
+        let matches = grid.match_pattern(&shape, b'?');
+        assert_eq!(
+            matches,
+            vec![PatternMatch {
+                row: 1,
+                col: 1,
+                orientation: Orientation {
+                    flipped: false,
+                    rotations: 3
+                },
+            }]
+        );
+    }
+}
@@ -1,5 +1,9 @@
+use super::{bigint, grid::Grid, ocr::recognize};
 use Solution::*;
+use std::cmp::Ordering;
+use std::convert::Infallible;
 use std::fmt::{Display, Formatter, Result};
+use std::str::FromStr;
 
 #[derive(Clone, Debug)]
 pub enum Solution {
@@ -15,7 +19,126 @@ pub enum Solution {
     U64(u64),
     U128(u128),
     Usize(usize),
+    BigInt(bigint::BigInt),
     Str(String),
+    Ascii(String),
+    Unsolved(Option<String>),
+}
+
+impl Solution {
+    // wraps a picture of block letters, see `utils::ocr`
+    pub fn ascii(picture: impl Into<String>) -> Self {
+        Ascii(picture.into())
+    }
+
+    // a part that isn't solved yet, with a note on what is missing
+    pub fn unsolved(note: impl Into<String>) -> Self {
+        Unsolved(Some(note.into()))
+    }
+
+    pub fn is_unsolved(&self) -> bool {
+        matches!(self, Unsolved(_))
+    }
+
+    // raw picture of an `Ascii` answer, for printing alongside the decoded text
+    pub fn picture(&self) -> Option<&str> {
+        match self {
+            Ascii(picture) => Some(picture),
+            _ => None,
+        }
+    }
+
+    // canonical form, integers become `I128` (or `U128`/`BigInt` when too large) and text is trimmed
+    // text holding an integer is treated as that integer
+    pub fn normalize(&self) -> Self {
+        match self.key() {
+            Key::Unsolved => self.clone(),
+            Key::Number(value) => {
+                if let Ok(value) = i128::try_from(&value) {
+                    I128(value)
+                } else if let Ok(value) = u128::try_from(&value) {
+                    U128(value)
+                } else {
+                    BigInt(value)
+                }
+            }
+            Key::Text(text) => Str(text),
+            Key::Picture(_) => self.clone(),
+        }
+    }
+
+    fn key(&self) -> Key {
+        match self {
+            I8(x) => Key::Number((*x).into()),
+            I16(x) => Key::Number((*x).into()),
+            I32(x) => Key::Number((*x).into()),
+            I64(x) => Key::Number((*x).into()),
+            I128(x) => Key::Number((*x).into()),
+            Isize(x) => Key::Number((*x).into()),
+            U8(x) => Key::Number((*x).into()),
+            U16(x) => Key::Number((*x).into()),
+            U32(x) => Key::Number((*x).into()),
+            U64(x) => Key::Number((*x).into()),
+            U128(x) => Key::Number((*x).into()),
+            Usize(x) => Key::Number((*x).into()),
+            BigInt(x) => Key::Number(x.clone()),
+            Str(x) => Key::parse(x),
+            Ascii(picture) => match recognize(picture) {
+                Some(text) => Key::Text(text),
+                // a trailing newline doesn't change the picture
+                None => Key::Picture(picture.trim_end_matches('\n').to_owned()),
+            },
+            Unsolved(_) => Key::Unsolved,
+        }
+    }
+}
+
+// Comparison key, unsolved parts sort first, then numbers by value, then text
+// and last pictures that couldn't be read, compared by their raw text
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+enum Key {
+    Unsolved,
+    Number(bigint::BigInt),
+    Text(String),
+    Picture(String),
+}
+
+impl Key {
+    fn parse(value: &str) -> Self {
+        let value = value.trim();
+        match value.parse() {
+            Ok(num) => Self::Number(num),
+            Err(_) => Self::Text(value.to_owned()),
+        }
+    }
+}
+
+impl PartialEq for Solution {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl Eq for Solution {}
+
+impl PartialOrd for Solution {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Solution {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key().cmp(&other.key())
+    }
+}
+
+impl FromStr for Solution {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(Str(s.to_owned()).normalize())
+    }
 }
 
 impl Display for Solution {
@@ -33,7 +156,14 @@ impl Display for Solution {
             U64(x) => x.fmt(f),
             U128(x) => x.fmt(f),
             Usize(x) => x.fmt(f),
+            BigInt(x) => x.fmt(f),
             Str(x) => x.fmt(f),
+            Ascii(x) => match recognize(x) {
+                Some(text) => text.fmt(f),
+                None => write!(f, "<unrecognized picture>"),
+            },
+            Unsolved(None) => write!(f, "pending"),
+            Unsolved(Some(note)) => write!(f, "pending ({note})"),
         }
     }
 }
@@ -62,8 +192,108 @@ impl_from!(u128, U128);
 impl_from!(usize, Usize);
 impl_from!(String, Str);
 
+impl From<bigint::BigInt> for Solution {
+    fn from(sol: bigint::BigInt) -> Self {
+        Self::BigInt(sol)
+    }
+}
+
 impl From<&str> for Solution {
     fn from(sol: &str) -> Self {
         Self::Str(sol.to_owned())
     }
 }
+
+impl From<&Grid> for Solution {
+    fn from(grid: &Grid) -> Self {
+        Self::ascii(grid.to_string().trim_end_matches('\n'))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eq_across_numeric_variants() {
+        assert_eq!(Solution::from(5i64), Solution::from(5u64));
+        assert_eq!(Solution::from(5u8), Solution::from(5usize));
+        assert_ne!(Solution::from(-5i32), Solution::from(5u32));
+    }
+
+    #[test]
+    fn test_eq_text_and_number() {
+        assert_eq!(Solution::from("42"), Solution::from(42i32));
+        assert_eq!(Solution::from(" 42\n"), Solution::from(42u64));
+        assert_ne!(Solution::from("forty two"), Solution::from(42u64));
+    }
+
+    #[test]
+    fn test_ord_across_numeric_variants() {
+        assert!(Solution::from(-1i8) < Solution::from(0u128));
+        assert!(Solution::from(i128::MIN) < Solution::from(-1i64));
+        assert!(Solution::from(i128::MAX as u128 + 1) > Solution::from(i128::MAX));
+        assert!(Solution::from(u128::MAX) < Solution::from("ABC"));
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert!(matches!("-12".parse::<Solution>(), Ok(Solution::I128(-12))));
+        assert!(matches!(
+            "340282366920938463463374607431768211455".parse::<Solution>(),
+            Ok(Solution::U128(u128::MAX))
+        ));
+        assert!(matches!(" HEB ".parse::<Solution>(), Ok(Solution::Str(s)) if s == "HEB"));
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert!(matches!(
+            Solution::from(7u16).normalize(),
+            Solution::I128(7)
+        ));
+        assert!(matches!(
+            Solution::from(i128::MIN).normalize(),
+            Solution::I128(i128::MIN)
+        ));
+    }
+
+    #[test]
+    fn test_big_int_variant() {
+        let big = bigint::BigInt::from(u128::MAX) + bigint::BigInt::from(1u8);
+        let parsed: Solution = "340282366920938463463374607431768211456".parse().unwrap();
+        assert!(matches!(parsed, Solution::BigInt(_)));
+        assert_eq!(parsed, Solution::from(big));
+        assert!(Solution::from(u128::MAX) < parsed);
+        assert_eq!(
+            Solution::from(bigint::BigInt::from(-3)),
+            Solution::from(-3i8)
+        );
+    }
+
+    #[test]
+    fn test_unsolved() {
+        assert_eq!(format!("{}", Solution::Unsolved(None)), "pending");
+        assert_eq!(format!("{}", Solution::unsolved("todo")), "pending (todo)");
+        assert_ne!(Solution::Unsolved(None), Solution::from(0));
+        assert_ne!(Solution::Unsolved(None), Solution::from("pending"));
+        assert!(Solution::Unsolved(None) < Solution::from(i128::MIN));
+    }
+
+    #[test]
+    fn test_ascii_eq_decoded_text() {
+        let picture = ".##.\n#..#\n#..#\n####\n#..#\n#..#";
+        assert_eq!(Solution::ascii(picture), Solution::from("A"));
+    }
+
+    #[test]
+    fn test_unrecognized_pictures_differ() {
+        let first = Solution::ascii("#.#\n.#.");
+        let second = Solution::ascii("###\n...");
+        assert_ne!(first, second);
+        assert_eq!(first, Solution::ascii("#.#\n.#."));
+        assert_ne!(first, Solution::from(first.to_string()));
+        assert_eq!(first, Solution::from(&Grid::new("#.#\n.#.")));
+        assert_eq!(first, Solution::ascii("#.#\n.#.\n"));
+    }
+}
@@ -0,0 +1,162 @@
+use std::collections::HashMap;
+
+use super::grid::Grid;
+use super::pos::Pos;
+
+// Unbounded grid keyed by signed position, only cells that were set are stored
+#[derive(Clone, Debug)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Pos, T>,
+    // inclusive bounding box of every stored cell, None when empty
+    bounds: Option<(Pos, Pos)>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.cells.get_mut(&pos)
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    // returns the previous value at this position
+    pub fn insert(&mut self, pos: Pos, value: T) -> Option<T> {
+        self.bounds = Some(match self.bounds {
+            Some((min, max)) => (
+                Pos::new(min.x.min(pos.x), min.y.min(pos.y)),
+                Pos::new(max.x.max(pos.x), max.y.max(pos.y)),
+            ),
+            None => (pos, pos),
+        });
+
+        self.cells.insert(pos, value)
+    }
+
+    pub fn remove(&mut self, pos: Pos) -> Option<T> {
+        let removed = self.cells.remove(&pos)?;
+
+        // only a cell on the edge of the bounding box can shrink it
+        if let Some((min, max)) = self.bounds
+            && (pos.x == min.x || pos.x == max.x || pos.y == min.y || pos.y == max.y)
+        {
+            self.bounds = self.compute_bounds();
+        }
+
+        Some(removed)
+    }
+
+    fn compute_bounds(&self) -> Option<(Pos, Pos)> {
+        let mut positions = self.cells.keys();
+        let first = *positions.next()?;
+        Some(positions.fold((first, first), |(min, max), pos| {
+            (
+                Pos::new(min.x.min(pos.x), min.y.min(pos.y)),
+                Pos::new(max.x.max(pos.x), max.y.max(pos.y)),
+            )
+        }))
+    }
+
+    // inclusive (top left, bottom right) corners around every stored cell
+    pub fn bounds(&self) -> Option<(Pos, Pos)> {
+        self.bounds
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&Pos, &T)> {
+        self.cells.iter()
+    }
+
+    // dense copy of the bounding box, with `fill` for cells that aren't stored
+    // also returns the position of the dense grid's top left cell
+    pub fn to_grid(&self, fill: T) -> (Grid<T>, Pos)
+    where
+        T: Clone,
+    {
+        let Some((min, max)) = self.bounds else {
+            return (Grid::from_vec(Vec::new(), 0), Pos::ORIGIN);
+        };
+
+        let data = (min.y..=max.y)
+            .flat_map(|y| (min.x..=max.x).map(move |x| Pos::new(x, y)))
+            .map(|pos| self.cells.get(&pos).unwrap_or(&fill).clone())
+            .collect();
+        let row_size = (max.x - min.x + 1) as usize;
+
+        (Grid::from_vec(data, row_size), min)
+    }
+}
+
+impl<T> FromIterator<(Pos, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Pos, T)>>(iter: I) -> Self {
+        let mut grid = SparseGrid::new();
+        for (pos, value) in iter {
+            grid.insert(pos, value);
+        }
+        grid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bounds_grow_with_inserts() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+
+        grid.insert(Pos::new(0, 0), b'#');
+        grid.insert(Pos::new(-3, 2), b'#');
+        grid.insert(Pos::new(5, -1), b'#');
+        assert_eq!(grid.bounds(), Some((Pos::new(-3, -1), Pos::new(5, 2))));
+        assert_eq!(grid.len(), 3);
+    }
+
+    #[test]
+    fn test_bounds_shrink_on_remove() {
+        let mut grid: SparseGrid<u8> = [(Pos::new(0, 0), b'a'), (Pos::new(4, 4), b'b')]
+            .into_iter()
+            .collect();
+        assert_eq!(grid.remove(Pos::new(4, 4)), Some(b'b'));
+        assert_eq!(grid.bounds(), Some((Pos::new(0, 0), Pos::new(0, 0))));
+        assert_eq!(grid.remove(Pos::new(4, 4)), None);
+        grid.remove(Pos::new(0, 0));
+        assert_eq!(grid.bounds(), None);
+    }
+
+    #[test]
+    fn test_to_grid() {
+        let grid: SparseGrid<u8> = [(Pos::new(-1, 0), b'#'), (Pos::new(1, 1), b'#')]
+            .into_iter()
+            .collect();
+        let (dense, origin) = grid.to_grid(b'.');
+        assert_eq!(origin, Pos::new(-1, 0));
+        assert_eq!(dense.to_string(), "#..\n..#\n");
+    }
+}
@@ -0,0 +1,240 @@
+use std::ops::Range;
+
+use super::grid::{Grid, Wrapping};
+
+#[allow(dead_code)]
+impl<T: Clone> Grid<T> {
+    // new grid of the given shape, filled from (row, col)
+    // turning the grid on its side swaps which edges wrap
+    fn reshaped<F>(&self, num_rows: usize, row_size: usize, swap_axes: bool, cell: F) -> Self
+    where
+        F: Fn(usize, usize) -> T,
+    {
+        let data = (0..num_rows)
+            .flat_map(|row| (0..row_size).map(move |col| (row, col)))
+            .map(|(row, col)| cell(row, col))
+            .collect();
+
+        let mut grid = Grid::from_vec(data, row_size);
+        grid.wrapping = self.wrapping;
+        if swap_axes {
+            grid.wrapping = Wrapping {
+                horizontal: self.wrapping.vertical,
+                vertical: self.wrapping.horizontal,
+            };
+        }
+        grid
+    }
+
+    // rows become columns
+    pub fn transpose(&self) -> Self {
+        let num_rows = self.num_rows();
+        self.reshaped(self.row_size, num_rows, true, |row, col| {
+            self[(col, row)].clone()
+        })
+    }
+
+    // a quarter turn clockwise, the first row becomes the last column
+    pub fn rotate_cw(&self) -> Self {
+        let num_rows = self.num_rows();
+        self.reshaped(self.row_size, num_rows, true, |row, col| {
+            self[(num_rows - 1 - col, row)].clone()
+        })
+    }
+
+    // a quarter turn counter clockwise, the first row becomes the first column
+    pub fn rotate_ccw(&self) -> Self {
+        let row_size = self.row_size;
+        self.reshaped(row_size, self.num_rows(), true, |row, col| {
+            self[(col, row_size - 1 - row)].clone()
+        })
+    }
+
+    // mirror left to right
+    pub fn flip_h(&self) -> Self {
+        let row_size = self.row_size;
+        self.reshaped(self.num_rows(), row_size, false, |row, col| {
+            self[(row, row_size - 1 - col)].clone()
+        })
+    }
+
+    // mirror top to bottom
+    pub fn flip_v(&self) -> Self {
+        let num_rows = self.num_rows();
+        self.reshaped(num_rows, self.row_size, false, |row, col| {
+            self[(num_rows - 1 - row, col)].clone()
+        })
+    }
+}
+
+#[allow(dead_code)]
+impl<T> Grid<T> {
+    // borrowed rectangle of the grid, ranges are clamped to the grid bounds
+    pub fn view(&self, rows: Range<usize>, cols: Range<usize>) -> GridView<'_, T> {
+        GridView::new(self, 0..self.num_rows(), 0..self.row_size).view(rows, cols)
+    }
+}
+
+// A rectangle borrowed from a grid, positions are relative to its top left corner
+#[derive(Clone, Debug)]
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    rows: Range<usize>,
+    cols: Range<usize>,
+}
+
+#[allow(dead_code)]
+impl<'a, T> GridView<'a, T> {
+    fn new(grid: &'a Grid<T>, rows: Range<usize>, cols: Range<usize>) -> Self {
+        Self { grid, rows, cols }
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn row_size(&self) -> usize {
+        self.cols.len()
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&'a T> {
+        if row >= self.num_rows() || col >= self.row_size() {
+            return None;
+        }
+        self.grid.get(self.rows.start + row, self.cols.start + col)
+    }
+
+    pub fn row(&self, row: usize) -> Option<&'a [T]> {
+        if row >= self.num_rows() {
+            return None;
+        }
+        let full_row = self.grid.row(self.rows.start + row)?;
+        Some(&full_row[self.cols.clone()])
+    }
+
+    pub fn iter_rows(&self) -> impl Iterator<Item = &'a [T]> {
+        let grid = self.grid;
+        let cols = self.cols.clone();
+        self.rows
+            .clone()
+            .map(move |row| &grid.row(row).unwrap()[cols.clone()])
+    }
+
+    pub fn col(&self, col: usize) -> Option<impl Iterator<Item = &'a T>> {
+        if col >= self.row_size() {
+            return None;
+        }
+        let grid = self.grid;
+        let col = self.cols.start + col;
+        Some(self.rows.clone().map(move |row| &grid[(row, col)]))
+    }
+
+    pub fn iter_cols(&self) -> impl Iterator<Item = impl Iterator<Item = &'a T>> {
+        let grid = self.grid;
+        let rows = self.rows.clone();
+        self.cols
+            .clone()
+            .map(move |col| rows.clone().map(move |row| &grid[(row, col)]))
+    }
+
+    // a smaller view, relative to this one and clamped to it
+    pub fn view(&self, rows: Range<usize>, cols: Range<usize>) -> GridView<'a, T> {
+        let clamp = |range: Range<usize>, bounds: &Range<usize>| {
+            let start = (bounds.start + range.start).min(bounds.end);
+            let end = (bounds.start + range.end).clamp(start, bounds.end);
+            start..end
+        };
+
+        GridView::new(self.grid, clamp(rows, &self.rows), clamp(cols, &self.cols))
+    }
+
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_vec(
+            self.iter_rows().flatten().cloned().collect(),
+            self.row_size(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transpose() {
+        let grid = Grid::new("abc\ndef");
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+    }
+
+    #[test]
+    fn test_rotate() {
+        let grid = Grid::new("abc\ndef");
+        assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.rotate_cw().rotate_ccw().to_string(), grid.to_string());
+    }
+
+    #[test]
+    fn test_flip() {
+        let grid = Grid::new("abc\ndef");
+        assert_eq!(grid.flip_h().to_string(), "cba\nfed\n");
+        assert_eq!(grid.flip_v().to_string(), "def\nabc\n");
+    }
+
+    #[test]
+    fn test_rotation_swaps_wrapping_axes() {
+        let grid = Grid::new("abc\ndef").with_wrapping(true, false);
+        assert_eq!(
+            grid.transpose().wrapping,
+            Wrapping {
+                horizontal: false,
+                vertical: true
+            }
+        );
+        assert_eq!(
+            grid.rotate_cw().wrapping,
+            Wrapping {
+                horizontal: false,
+                vertical: true
+            }
+        );
+        assert_eq!(grid.flip_v().wrapping, grid.wrapping);
+    }
+
+    #[test]
+    fn test_view_get() {
+        let grid = Grid::new("abcd\nefgh\nijkl");
+        let view = grid.view(1..3, 1..3);
+        assert_eq!(view.num_rows(), 2);
+        assert_eq!(view.row_size(), 2);
+        assert_eq!(view.get(0, 0), Some(&b'f'));
+        assert_eq!(view.get(1, 1), Some(&b'k'));
+        assert_eq!(view.get(0, 2), None);
+        assert_eq!(view.get(2, 0), None);
+    }
+
+    #[test]
+    fn test_view_rows_and_cols() {
+        let grid = Grid::new("abcd\nefgh\nijkl");
+        let view = grid.view(0..2, 2..4);
+        let rows: Vec<&[u8]> = view.iter_rows().collect();
+        assert_eq!(rows, vec![&b"cd"[..], &b"gh"[..]]);
+
+        let cols: Vec<Vec<u8>> = view.iter_cols().map(|col| col.copied().collect()).collect();
+        assert_eq!(cols, vec![b"cg".to_vec(), b"dh".to_vec()]);
+        assert_eq!(view.to_grid().to_string(), "cd\ngh\n");
+    }
+
+    #[test]
+    fn test_view_is_clamped() {
+        let grid = Grid::new("abcd\nefgh\nijkl");
+        let view = grid.view(2..10, 3..10);
+        assert_eq!(view.to_grid().to_string(), "l\n");
+
+        let nested = grid.view(1..3, 1..4).view(1..5, 0..2);
+        assert_eq!(nested.to_grid().to_string(), "jk\n");
+    }
+}
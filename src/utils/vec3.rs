@@ -0,0 +1,375 @@
+use std::collections::{HashSet, VecDeque};
+use std::fmt::{self, Display};
+use std::num::ParseIntError;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+// Signed position or offset in 3D space
+#[derive(Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub struct Vector3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+// Offsets to the 6 face neighbours of a voxel
+pub const FACE_OFFSETS: [Vector3; 6] = [
+    Vector3::new(1, 0, 0),
+    Vector3::new(-1, 0, 0),
+    Vector3::new(0, 1, 0),
+    Vector3::new(0, -1, 0),
+    Vector3::new(0, 0, 1),
+    Vector3::new(0, 0, -1),
+];
+
+#[allow(dead_code)]
+impl Vector3 {
+    pub const ORIGIN: Vector3 = Vector3::new(0, 0, 0);
+
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn manhattan(&self, other: &Vector3) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    // no square root, still fine for comparing and sorting distances
+    pub fn distance_squared(&self, other: &Vector3) -> i64 {
+        let diff = *self - *other;
+        diff.x.pow(2) + diff.y.pow(2) + diff.z.pow(2)
+    }
+
+    // the voxels sharing a face with this one
+    pub fn neighbors6(&self) -> impl Iterator<Item = Vector3> + use<> {
+        let pos = *self;
+        FACE_OFFSETS.iter().map(move |&offset| pos + offset)
+    }
+
+    // the voxels sharing a face, edge or corner with this one
+    pub fn neighbors26(&self) -> impl Iterator<Item = Vector3> + use<> {
+        let pos = *self;
+        (-1..=1)
+            .flat_map(|x| (-1..=1).flat_map(move |y| (-1..=1).map(move |z| Vector3::new(x, y, z))))
+            .filter(|&offset| offset != Vector3::ORIGIN)
+            .map(move |offset| pos + offset)
+    }
+
+    // smallest value of each component
+    pub fn component_min(&self, other: &Vector3) -> Vector3 {
+        Vector3::new(
+            self.x.min(other.x),
+            self.y.min(other.y),
+            self.z.min(other.z),
+        )
+    }
+
+    // largest value of each component
+    pub fn component_max(&self, other: &Vector3) -> Vector3 {
+        Vector3::new(
+            self.x.max(other.x),
+            self.y.max(other.y),
+            self.z.max(other.z),
+        )
+    }
+}
+
+impl Add for Vector3 {
+    type Output = Vector3;
+
+    fn add(self, rhs: Vector3) -> Vector3 {
+        Vector3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl Sub for Vector3 {
+    type Output = Vector3;
+
+    fn sub(self, rhs: Vector3) -> Vector3 {
+        Vector3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl Neg for Vector3 {
+    type Output = Vector3;
+
+    fn neg(self) -> Vector3 {
+        Vector3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl Mul<i64> for Vector3 {
+    type Output = Vector3;
+
+    fn mul(self, rhs: i64) -> Vector3 {
+        Vector3::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl AddAssign for Vector3 {
+    fn add_assign(&mut self, rhs: Vector3) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Vector3 {
+    fn sub_assign(&mut self, rhs: Vector3) {
+        *self = *self - rhs;
+    }
+}
+
+impl Display for Vector3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ParseVector3Error {
+    WrongComponentCount(usize),
+    InvalidNumber(ParseIntError),
+}
+
+impl Display for ParseVector3Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseVector3Error::WrongComponentCount(count) => {
+                write!(f, "expected `x,y,z`, found {count} components")
+            }
+            ParseVector3Error::InvalidNumber(err) => write!(f, "invalid coordinate: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ParseVector3Error {}
+
+// parses `x,y,z`
+impl FromStr for Vector3 {
+    type Err = ParseVector3Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let nums = s
+            .split(',')
+            .map(|num| num.trim().parse().map_err(ParseVector3Error::InvalidNumber))
+            .collect::<Result<Vec<i64>, _>>()?;
+
+        match nums[..] {
+            [x, y, z] => Ok(Vector3::new(x, y, z)),
+            _ => Err(ParseVector3Error::WrongComponentCount(nums.len())),
+        }
+    }
+}
+
+// Dense box of voxels from `min` to `max` inclusive, every cell is stored
+#[derive(Clone, Debug)]
+pub struct VoxelGrid<T> {
+    min: Vector3,
+    max: Vector3,
+    data: Vec<T>,
+}
+
+#[allow(dead_code)]
+impl<T: Clone> VoxelGrid<T> {
+    pub fn new(min: Vector3, max: Vector3, fill: T) -> Self {
+        let size = max - min + Vector3::new(1, 1, 1);
+        let len = (size.x.max(0) * size.y.max(0) * size.z.max(0)) as usize;
+        Self {
+            min,
+            max,
+            data: vec![fill; len],
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl<T> VoxelGrid<T> {
+    // inclusive (min, max) corners
+    pub fn bounds(&self) -> (Vector3, Vector3) {
+        (self.min, self.max)
+    }
+
+    pub fn contains(&self, pos: Vector3) -> bool {
+        self.index_of(pos).is_some()
+    }
+
+    pub fn get(&self, pos: Vector3) -> Option<&T> {
+        self.data.get(self.index_of(pos)?)
+    }
+
+    // returns the previous value, or None when `pos` is outside the box
+    pub fn set(&mut self, pos: Vector3, value: T) -> Option<T> {
+        let i = self.index_of(pos)?;
+        Some(std::mem::replace(&mut self.data[i], value))
+    }
+
+    fn index_of(&self, pos: Vector3) -> Option<usize> {
+        if pos.component_min(&self.min) != self.min || pos.component_max(&self.max) != self.max {
+            return None;
+        }
+        let size = self.max - self.min + Vector3::new(1, 1, 1);
+        let offset = pos - self.min;
+        Some(((offset.z * size.y + offset.y) * size.x + offset.x) as usize)
+    }
+}
+
+// Sparse set of filled voxels, only the filled ones are stored
+#[derive(Clone, Debug, Default)]
+pub struct VoxelSet {
+    cells: HashSet<Vector3>,
+}
+
+#[allow(dead_code)]
+impl VoxelSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // true if the voxel wasn't already filled
+    pub fn insert(&mut self, pos: Vector3) -> bool {
+        self.cells.insert(pos)
+    }
+
+    pub fn contains(&self, pos: Vector3) -> bool {
+        self.cells.contains(&pos)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Vector3> {
+        self.cells.iter()
+    }
+
+    // inclusive (min, max) corners around every filled voxel
+    pub fn bounds(&self) -> Option<(Vector3, Vector3)> {
+        let mut cells = self.cells.iter();
+        let first = *cells.next()?;
+        Some(cells.fold((first, first), |(min, max), pos| {
+            (min.component_min(pos), max.component_max(pos))
+        }))
+    }
+
+    // dense copy of the bounding box, true where filled
+    pub fn to_dense(&self) -> Option<VoxelGrid<bool>> {
+        let (min, max) = self.bounds()?;
+        let mut dense = VoxelGrid::new(min, max, false);
+        for &pos in &self.cells {
+            dense.set(pos, true);
+        }
+        Some(dense)
+    }
+
+    // faces not touching another filled voxel, including faces of trapped air pockets
+    pub fn surface_area(&self) -> usize {
+        self.cells
+            .iter()
+            .flat_map(|pos| pos.neighbors6())
+            .filter(|neighbor| !self.contains(*neighbor))
+            .count()
+    }
+
+    // faces reachable from outside, found by flood filling the air around the shape
+    pub fn exterior_surface_area(&self) -> usize {
+        let Some((min, max)) = self.bounds() else {
+            return 0;
+        };
+
+        // one voxel of air all the way round so the fill can get to every side
+        let margin = Vector3::new(1, 1, 1);
+        let mut outside = VoxelGrid::new(min - margin, max + margin, false);
+        let start = min - margin;
+        outside.set(start, true);
+
+        let mut queue = VecDeque::from([start]);
+        let mut faces = 0;
+        while let Some(pos) = queue.pop_front() {
+            for neighbor in pos.neighbors6() {
+                if self.contains(neighbor) {
+                    faces += 1;
+                } else if outside.set(neighbor, true) == Some(false) {
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        faces
+    }
+}
+
+impl FromIterator<Vector3> for VoxelSet {
+    fn from_iter<I: IntoIterator<Item = Vector3>>(iter: I) -> Self {
+        Self {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 13 cubes from a lava droplet puzzle, with one air pocket trapped inside
+    const DROPLET: &str =
+        "2,2,2\n1,2,2\n3,2,2\n2,1,2\n2,3,2\n2,2,1\n2,2,3\n2,2,4\n2,2,6\n1,2,5\n3,2,5\n2,1,5\n2,3,5";
+
+    fn droplet() -> VoxelSet {
+        DROPLET.lines().map(|line| line.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("1,-2,3".parse(), Ok(Vector3::new(1, -2, 3)));
+        assert_eq!(
+            "1,2".parse::<Vector3>(),
+            Err(ParseVector3Error::WrongComponentCount(2))
+        );
+        assert!(matches!(
+            "1,x,3".parse::<Vector3>(),
+            Err(ParseVector3Error::InvalidNumber(_))
+        ));
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Vector3::new(1, 2, 3);
+        let b = Vector3::new(-2, 6, 3);
+        assert_eq!(a.manhattan(&b), 7);
+        assert_eq!(a.distance_squared(&b), 25);
+        assert_eq!(a - b, Vector3::new(3, -4, 0));
+        assert_eq!(-a * 2, Vector3::new(-2, -4, -6));
+    }
+
+    #[test]
+    fn test_neighborhoods() {
+        let pos = Vector3::new(5, 5, 5);
+        assert!(pos.neighbors6().all(|n| n.manhattan(&pos) == 1));
+        let around: HashSet<Vector3> = pos.neighbors26().collect();
+        assert_eq!(around.len(), 26);
+        assert!(!around.contains(&pos));
+    }
+
+    #[test]
+    fn test_voxel_grid() {
+        let mut grid = VoxelGrid::new(Vector3::new(-1, -1, -1), Vector3::new(1, 1, 1), 0);
+        assert_eq!(grid.set(Vector3::ORIGIN, 7), Some(0));
+        assert_eq!(grid.get(Vector3::ORIGIN), Some(&7));
+        assert_eq!(grid.get(Vector3::new(2, 0, 0)), None);
+        assert_eq!(grid.set(Vector3::new(0, 0, -2), 1), None);
+    }
+
+    #[test]
+    fn test_surface_area() {
+        let pair: VoxelSet = [Vector3::new(1, 1, 1), Vector3::new(2, 1, 1)]
+            .into_iter()
+            .collect();
+        assert_eq!(pair.surface_area(), 10);
+
+        assert_eq!(droplet().surface_area(), 64);
+        assert_eq!(droplet().exterior_surface_area(), 58);
+    }
+}